@@ -1,13 +1,15 @@
 use super::util;
 use crate::info::{
-    Container, Field, FieldKind, GroupType, SetterKind, SolverKind, TrackedField, TrackedFieldKind,
+    Constraint, Container, Expression, Field, FieldKind, Group, GroupType, SetterKind, SolverKind,
+    TrackedField, TrackedFieldKind,
 };
+use convert_case::{Case, Casing};
 use itertools::{Itertools, Powerset};
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Literal, Span, TokenStream};
 use proc_macro_error::emit_error;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use std::{collections::BTreeSet, ops::Deref};
-use syn::{parse_quote, GenericParam};
+use syn::GenericParam;
 
 pub struct BuilderGenerator<'info> {
     info: &'info Container<'info>,
@@ -52,11 +54,15 @@ impl<'info> BuilderGenerator<'info> {
 
         let (_, type_generics, _) = self.info.generics().split_for_impl();
 
-        let vis = self.info.vis();
+        let vis = if self.info.generate_module() {
+            util::vis_in_module(self.info.builder_vis())
+        } else {
+            self.info.builder_vis().clone()
+        };
 
         let documentation = format!(
             "Builder for [`{}`] derived using the `const_typed_builder` crate",
-            self.info.ident()
+            self.info.target_name()
         );
 
         quote!(
@@ -67,16 +73,23 @@ impl<'info> BuilderGenerator<'info> {
         )
     }
 
-    /// Generates the implementation code for the builder struct's `new`, `build` and setter methods.
+    /// Generates the implementation code for the builder struct's `new`, `build`, setter, clear and getter methods,
+    /// and the conversion from the target into the builder.
     fn generate_impl(&self) -> TokenStream {
         let builder_setters = self.generate_setters_impl();
+        let builder_clears = self.generate_clears_impl();
+        let builder_getters = self.generate_getters_impl();
         let builder_new = self.generate_new_impl();
         let builder_build = self.generate_build_impl();
+        let builder_from = self.generate_from_impl();
 
         quote!(
             #builder_new
             #builder_setters
+            #builder_clears
+            #builder_getters
             #builder_build
+            #builder_from
         )
     }
 
@@ -86,11 +99,8 @@ impl<'info> BuilderGenerator<'info> {
         let data_ident = self.info.data_ident();
         let data_field = self.info.data_field_ident();
 
-        let type_generics = util::const_generics_all_valued(
-            false,
-            self.info.field_collection(),
-            self.info.generics(),
-        );
+        let type_generics =
+            util::const_generics_initial(self.info.field_collection(), self.info.generics());
         let (impl_generics, _, where_clause) = self.info.generics().split_for_impl();
         let documentation =
             format!("Creates a new [`{builder_ident}`] without any fields initialized");
@@ -114,103 +124,1028 @@ impl<'info> BuilderGenerator<'info> {
         )
     }
 
-    /// Generates the code for the `build` method implementation.
-    fn generate_build_impl(&self) -> TokenStream {
+    /// Generates the code for the `build` method implementation.
+    fn generate_build_impl(&self) -> TokenStream {
+        let builder_ident = self.info.builder_ident();
+        let data_field = self.info.data_field_ident();
+
+        let where_clause = &self.info.generics().where_clause;
+
+        match self.info.solver_kind() {
+            SolverKind::BruteForce => {
+                let generics = self.const_generic_optional_params();
+                let (impl_generics, _, _) = generics.split_for_impl();
+                let build_fns = self.generate_build_fns(quote!(self.#data_field.into()));
+                let count_combinations = self.valid_count_combinations();
+                let build_impls = self
+                    .valid_groupident_combinations()
+                    .cartesian_product(count_combinations)
+                    .map(|(group_indices, counts)| {
+                        let type_generics =
+                            self.const_generic_idents_build(&group_indices, &counts);
+                        let buildable_impl = self.generate_buildable_impl(
+                            &impl_generics,
+                            &type_generics,
+                            where_clause,
+                        );
+
+                        quote!(
+                            impl #impl_generics #builder_ident #type_generics #where_clause{
+                                #build_fns
+                            }
+                            #buildable_impl
+                        )
+                    });
+
+                quote!(
+                    #(#build_impls)*
+                )
+            }
+            SolverKind::Compiler => {
+                let impl_generics = self.const_generic_group_partial_idents();
+
+                let correctness_verifier = self.impl_correctness_verifier();
+                let correctness_check = self.impl_correctness_check();
+                let correctness_helper_fns = self.impl_correctness_helper_fns();
+
+                let build_fns = self.generate_build_fns(quote!(
+                    #correctness_check
+                    self.#data_field.into()
+                ));
+
+                let build_impls = self.valid_count_combinations().into_iter().map(|counts| {
+                    let type_generics = self.const_generic_idents_build_unset_group(&counts);
+                    let buildable_impl =
+                        self.generate_buildable_impl(&impl_generics, &type_generics, where_clause);
+
+                    quote!(
+                        impl #impl_generics #builder_ident #type_generics #where_clause{
+                            #correctness_verifier
+                            #correctness_helper_fns
+
+                            #build_fns
+                        }
+                        #buildable_impl
+                    )
+                });
+
+                quote!(
+                    #(#build_impls)*
+                )
+            }
+            SolverKind::Counting => {
+                let generics = self.const_generic_group_partial_idents();
+                let (impl_generics, _, _) = generics.split_for_impl();
+                let where_clause = self.counting_where_clause();
+                let counting_items = self.generate_counting_items();
+                let build_fns = self.generate_build_fns(quote!(self.#data_field.into()));
+
+                let build_impls = self.valid_count_combinations().into_iter().map(|counts| {
+                    let type_generics = self.const_generic_idents_build_unset_group(&counts);
+                    let buildable_impl =
+                        self.generate_buildable_impl(&impl_generics, &type_generics, &where_clause);
+
+                    quote!(
+                        impl #impl_generics #builder_ident #type_generics #where_clause {
+                            #build_fns
+                        }
+                        #buildable_impl
+                    )
+                });
+
+                quote!(
+                    #counting_items
+                    #(#build_impls)*
+                )
+            }
+        }
+    }
+
+    /// Generates the items used by the counting solver, which checks the groups using the trait system.
+    ///
+    /// A type-level counter is incremented for every set field of a group, by implementing a trait for every step of the counter.
+    /// Every group has a trait that is only implemented for the counts that satisfy the group.
+    /// This grows linearly with the number of fields in a group, instead of exponentially with the number of grouped fields.
+    /// Every constraint has a trait that is only implemented for the tuples of counters of its fields that satisfy it.
+    fn generate_counting_items(&self) -> TokenStream {
+        if self.info.group_collection().is_empty() && self.info.constraint_collection().is_empty() {
+            return TokenStream::new();
+        }
+        let vis = if self.info.generate_module() {
+            util::vis_in_module(self.info.builder_vis())
+        } else {
+            self.info.builder_vis().clone()
+        };
+        let count_ident = self.counting_count_ident();
+        let add_ident = self.counting_add_ident();
+
+        let max_members = self
+            .info
+            .group_collection()
+            .values()
+            .map(|group| group.indices().len())
+            .chain((!self.info.constraint_collection().is_empty()).then_some(1))
+            .max()
+            .unwrap_or_default();
+        let add_impls = (0..max_members).map(|count| {
+            let current = Literal::usize_unsuffixed(count);
+            let next = Literal::usize_unsuffixed(count + 1);
+            quote!(
+                impl #add_ident<true> for #count_ident<#current> {
+                    type Output = #count_ident<#next>;
+                }
+            )
+        });
+
+        let group_items = self.info.group_collection().values().map(|group| {
+            let group_ident = self.counting_group_ident(group);
+            let valid_counts = (0..=group.indices().len())
+                .filter(|count| group.is_valid_count(*count))
+                .map(Literal::usize_unsuffixed);
+            let documentation = format!(
+                "Implemented for the counts of set fields that satisfy the group `{}`",
+                group.ident()
+            );
+            quote!(
+                #[doc = #documentation]
+                #[doc(hidden)]
+                #[allow(dead_code)]
+                #vis trait #group_ident {}
+                #(impl #group_ident for #count_ident<#valid_counts> {})*
+            )
+        });
+
+        let constraint_items = self
+            .info
+            .constraint_collection()
+            .iter()
+            .enumerate()
+            .map(|(index, constraint)| {
+                let constraint_ident = self.counting_constraint_ident(index);
+                let indices = constraint.indices();
+                let valid_states = constraint.valid_combinations().map(|set| {
+                    let counts = indices
+                        .iter()
+                        .map(|index| Literal::usize_unsuffixed(set.contains(index).into()));
+                    quote!((#(#count_ident<#counts>,)*))
+                });
+                let documentation = format!(
+                    "Implemented for the states of the fields that satisfy the constraint {constraint}"
+                );
+                quote!(
+                    #[doc = #documentation]
+                    #[doc(hidden)]
+                    #[allow(dead_code)]
+                    #vis trait #constraint_ident {}
+                    #(impl #constraint_ident for #valid_states {})*
+                )
+            });
+
+        quote!(
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            #vis struct #count_ident<const N: usize>;
+
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            #vis trait #add_ident<const SET: bool> {
+                type Output;
+            }
+
+            impl<const N: usize> #add_ident<false> for #count_ident<N> {
+                type Output = Self;
+            }
+            #(#add_impls)*
+            #(#group_items)*
+            #(#constraint_items)*
+        )
+    }
+
+    /// Generates the where clause of the impls of the counting solver, which contains the where clause of the target
+    /// and the bounds that check the groups.
+    ///
+    /// For every group the counter starts at zero, and is passed through the const generics of its fields.
+    /// The resulting count has to implement the trait of the group.
+    fn counting_where_clause(&self) -> TokenStream {
+        let count_ident = self.counting_count_ident();
+        let add_ident = self.counting_add_ident();
+
+        let target_predicates = self
+            .info
+            .generics()
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+            .map(ToTokens::to_token_stream);
+        let group_predicates = self.info.group_collection().values().flat_map(|group| {
+            let group_ident = self.counting_group_ident(group);
+            let mut count = quote!(#count_ident<0>);
+            let mut predicates = Vec::new();
+            for index in group.indices() {
+                let const_ident = self
+                    .info
+                    .field_collection()
+                    .get(*index)
+                    .expect("Could not find field associated to group")
+                    .const_ident();
+                predicates.push(quote!(#count: #add_ident<#const_ident>));
+                count = quote!(<#count as #add_ident<#const_ident>>::Output);
+            }
+            predicates.push(quote!(#count: #group_ident));
+            predicates
+        });
+        let constraint_predicates = self
+            .info
+            .constraint_collection()
+            .iter()
+            .enumerate()
+            .flat_map(|(index, constraint)| {
+                let constraint_ident = self.counting_constraint_ident(index);
+                let const_idents = self.constraint_const_idents(constraint);
+                let mut predicates = const_idents
+                    .iter()
+                    .map(|const_ident| quote!(#count_ident<0>: #add_ident<#const_ident>))
+                    .collect_vec();
+                let counts = const_idents.iter().map(
+                    |const_ident| quote!(<#count_ident<0> as #add_ident<#const_ident>>::Output),
+                );
+                predicates.push(quote!((#(#counts,)*): #constraint_ident));
+                predicates
+            });
+
+        let predicates = target_predicates
+            .chain(group_predicates)
+            .chain(constraint_predicates)
+            .collect_vec();
+        if predicates.is_empty() {
+            TokenStream::new()
+        } else {
+            quote!(where #(#predicates),*)
+        }
+    }
+
+    /// Retrieves the identifier of the type-level counter of the counting solver.
+    fn counting_count_ident(&self) -> syn::Ident {
+        format_ident!("__{}Count", self.info.builder_ident())
+    }
+
+    /// Retrieves the identifier of the trait that increments the type-level counter of the counting solver.
+    fn counting_add_ident(&self) -> syn::Ident {
+        format_ident!("__{}Add", self.info.builder_ident())
+    }
+
+    /// Retrieves the identifier of the trait that checks a group in the counting solver.
+    fn counting_group_ident(&self, group: &Group) -> syn::Ident {
+        format_ident!(
+            "__{}Group{}",
+            self.info.builder_ident(),
+            group.ident().to_string().to_case(Case::Pascal)
+        )
+    }
+
+    /// Retrieves the identifier of the trait that checks a constraint in the counting solver.
+    fn counting_constraint_ident(&self, index: usize) -> syn::Ident {
+        format_ident!("__{}Constraint{}", self.info.builder_ident(), index)
+    }
+
+    /// Retrieves the const generic identifiers of all fields a constraint depends on, ordered by their index.
+    fn constraint_const_idents(&self, constraint: &Constraint) -> Vec<syn::Ident> {
+        constraint
+            .indices()
+            .into_iter()
+            .map(|index| {
+                self.info
+                    .field_collection()
+                    .get(index)
+                    .expect("Could not find field associated to constraint")
+                    .const_ident()
+            })
+            .collect()
+    }
+
+    /// Generates the code for the conversion from the target into the builder, in a state in which every field is set.
+    ///
+    /// The state of grouped fields and counted collections depends on the values of the target, which are only known at runtime.
+    /// If the target has any of these, or is an enum variant, `TryFrom` is implemented for every state in which the builder can be built.
+    /// The conversion succeeds for the state that matches the values of the target, and returns the target otherwise.
+    /// The counting and compiler solvers implement `TryFrom` generically over the state of the grouped fields.
+    /// The counting solver bounds the state by its where clause, and the compiler solver checks the groups at runtime.
+    /// In all other cases `From` is implemented for the single state in which every field is set.
+    fn generate_from_impl(&self) -> TokenStream {
+        let builder_ident = self.info.builder_ident();
+        let data_ident = self.info.data_ident();
+        let data_field = self.info.data_field_ident();
+        let target_ident = self.info.ident();
+        let target_path = self.info.target_path();
+        let (impl_generics, target_type_generics, where_clause) =
+            self.info.generics().split_for_impl();
+        let target = quote!(#target_ident #target_type_generics);
+
+        let fields = self
+            .info
+            .field_collection()
+            .iter()
+            .filter(|field| field.kind() != FieldKind::Skipped);
+        let bindings = fields.clone().map(|field| {
+            let field_ident = field.ident();
+            match field.member() {
+                syn::Member::Named(_) => quote!(#field_ident),
+                member @ syn::Member::Unnamed(_) => quote!(#member: #field_ident),
+            }
+        });
+        let data_fields = fields.map(|field| {
+            let field_ident = field.ident();
+            let value = util::data_field_from_value(field, quote!(#field_ident));
+            quote!(#field_ident: #value)
+        });
+        let phantom = (!self.info.generics().params.is_empty())
+            .then(|| quote!(__phantom: ::core::marker::PhantomData,));
+        let pattern = quote!(#target_path { #(#bindings,)* .. });
+        let builder = quote!(
+            #builder_ident {
+                #data_field: #data_ident {
+                    #(#data_fields,)*
+                    #phantom
+                }
+            }
+        );
+
+        let has_runtime_state = self.info.variant().is_some()
+            || !self.info.group_collection().is_empty()
+            || !self.info.constraint_collection().is_empty()
+            || self
+                .info
+                .field_collection()
+                .iter()
+                .any(|field| field.is_counted());
+
+        if !has_runtime_state {
+            let type_generics = self.const_generic_idents_from(Some(&[]), &[]);
+            let documentation = format!(
+                "Converts an instance of [`{}`] into a [`{builder_ident}`] in which every field is set",
+                self.info.target_name()
+            );
+            return quote!(
+                impl #impl_generics ::core::convert::From<#target> for #builder_ident #type_generics #where_clause {
+                    #[doc = #documentation]
+                    fn from(value: #target) -> Self {
+                        let #pattern = value;
+                        #builder
+                    }
+                }
+            );
+        }
+
+        let documentation = format!(
+            "Converts an instance of [`{}`] into a [`{builder_ident}`] in which every field is set, if the fields match this state of the builder",
+            self.info.target_name()
+        );
+        // `None` leaves the state of the grouped fields generic
+        let group_states = match self.info.solver_kind() {
+            SolverKind::BruteForce => self.valid_groupident_combinations().map(Some).collect(),
+            SolverKind::Compiler | SolverKind::Counting => vec![None],
+        };
+        let from_impls = group_states
+            .into_iter()
+            .cartesian_product(self.valid_count_combinations())
+            .map(|(group_indices, counts)| {
+                let type_generics = self.const_generic_idents_from(group_indices.as_deref(), &counts);
+                let (impl_generics, where_clause) = match group_indices {
+                    Some(_) => (impl_generics.to_token_stream(), where_clause.to_token_stream()),
+                    None => {
+                        let generics = self.const_generic_group_params();
+                        let (impl_generics, _, _) = generics.split_for_impl();
+                        let where_clause = match self.info.solver_kind() {
+                            SolverKind::Counting => self.counting_where_clause(),
+                            SolverKind::BruteForce | SolverKind::Compiler => {
+                                where_clause.to_token_stream()
+                            }
+                        };
+                        (impl_generics.to_token_stream(), where_clause)
+                    }
+                };
+                let group_conditions = self
+                    .info
+                    .field_collection()
+                    .iter()
+                    .filter(|field| field.kind() == FieldKind::Grouped)
+                    .map(|field| {
+                        let field_ident = field.ident();
+                        match &group_indices {
+                            Some(group_indices) if group_indices.contains(&field.index()) => {
+                                quote!(#field_ident.is_some())
+                            }
+                            Some(_) => quote!(#field_ident.is_none()),
+                            None => {
+                                let const_ident = field.const_ident();
+                                quote!(#field_ident.is_some() == #const_ident)
+                            }
+                        }
+                    });
+                let count_conditions = counts.iter().map(|(index, count)| {
+                    let field = self
+                        .info
+                        .field_collection()
+                        .get(*index)
+                        .expect("Could not find field associated to count");
+                    let field_ident = field.ident();
+                    let len = quote!(::core::iter::Iterator::count(::core::iter::IntoIterator::into_iter(&#field_ident)));
+                    // Without a maximum the count saturates at the minimum, so any number of items above it is in the same state
+                    if *count == field.count_cap() && field.can_add_item(*count) {
+                        quote!(#len >= #count)
+                    } else {
+                        quote!(#len == #count)
+                    }
+                });
+
+                // The compiler solver only checks the groups when building, so the state of the grouped fields is checked here
+                let solver_conditions = match (self.info.solver_kind(), &group_indices) {
+                    (SolverKind::Compiler, None) => self.compiler_conditions(),
+                    _ => Vec::new(),
+                };
+
+                let conditions = group_conditions
+                    .chain(count_conditions)
+                    .chain(solver_conditions)
+                    .collect_vec();
+                let guard = (!conditions.is_empty()).then(|| quote!(if #(#conditions)&&*));
+
+                quote!(
+                    impl #impl_generics ::core::convert::TryFrom<#target> for #builder_ident #type_generics #where_clause {
+                        type Error = #target;
+
+                        #[doc = #documentation]
+                        #[allow(unreachable_patterns)]
+                        fn try_from(value: #target) -> ::core::result::Result<Self, Self::Error> {
+                            match value {
+                                #pattern #guard => ::core::result::Result::Ok(#builder),
+                                value => ::core::result::Result::Err(value),
+                            }
+                        }
+                    }
+                )
+            });
+
+        quote!(
+            #(#from_impls)*
+        )
+    }
+
+    /// Generates the implementation of the `Buildable` trait for a state of the builder in which it can be built,
+    /// which is used to build the builders returned by the closures of propagated fields.
+    /// The trait is only implemented if the build method is as visible as the builder, as it would expose the build method otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// - `impl_generics`: The generics of the impl of the build method.
+    /// - `type_generics`: The generics of the builder in the state in which it can be built.
+    /// - `where_clause`: The where clause of the impl of the build method.
+    fn generate_buildable_impl(
+        &self,
+        impl_generics: &impl ToTokens,
+        type_generics: &TokenStream,
+        where_clause: &impl ToTokens,
+    ) -> TokenStream {
+        if util::is_less_visible(&self.info.build_fn_vis(), self.info.builder_vis()) {
+            return TokenStream::new();
+        }
+        let builder_ident = self.info.builder_ident();
+        let build_ident = self.info.build_fn_ident();
+        let output = self.build_fn_output();
+
+        quote!(
+            impl #impl_generics ::const_typed_builder::Buildable for #builder_ident #type_generics #where_clause {
+                type Target = #output;
+
+                fn build(self) -> Self::Target {
+                    self.#build_ident()
+                }
+            }
+        )
+    }
+
+    /// Retrieves the type returned by the build method, which is a `Result` if the target is validated.
+    fn build_fn_output(&self) -> TokenStream {
+        let target_ident = self.info.ident();
+        let (_, target_type_generics, _) = self.info.generics().split_for_impl();
+        let target = quote!(#target_ident #target_type_generics);
+        match &self.info.build_fn_settings().validate {
+            Some(_) => {
+                let error = self.info.error_ty();
+                quote!(::core::result::Result<#target, #error>)
+            }
+            None => target,
+        }
+    }
+
+    /// Generates the code for the build method and the additional build methods that are enabled using `#[builder(build_fn(...))]`.
+    /// If the target is validated, all build methods are fallible and return a `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// - `body`: The body of the build method, which evaluates to the target.
+    fn generate_build_fns(&self, body: TokenStream) -> TokenStream {
+        let builder_ident = self.info.builder_ident();
+        let target_ident = self.info.ident();
+        let target_name = self.info.target_name();
+        let (_, target_type_generics, _) = self.info.generics().split_for_impl();
+        let target = quote!(#target_ident #target_type_generics);
+
+        let build_ident = self.info.build_fn_ident();
+        let vis = self.info.build_fn_vis();
+        let settings = self.info.build_fn_settings();
+
+        let error = settings.validate.as_ref().map(|_| self.info.error_ty());
+        let output = |ty: TokenStream| match &error {
+            Some(error) => quote!(::core::result::Result<#ty, #error>),
+            None => ty,
+        };
+        let convert = |func: TokenStream| match &error {
+            Some(_) => quote!(self.#build_ident().map(#func)),
+            None => quote!(#func(self.#build_ident())),
+        };
+        let fallible_doc = if error.is_some() {
+            ", returns an error if the validation failed"
+        } else {
+            ""
+        };
+
+        let documentation = format!(
+            "Build an instance of [`{target_name}`], consuming the [`{builder_ident}`]{fallible_doc}"
+        );
+        let build_output = self.build_fn_output();
+        let build_body = match &settings.validate {
+            Some(validate) => quote!(
+                let target: #target = { #body };
+                #validate(&target)?;
+                ::core::result::Result::Ok(target)
+            ),
+            None => body,
+        };
+
+        let boxed = settings.boxed.then(|| {
+            let ident = format_ident!("{build_ident}_boxed");
+            let documentation = format!(
+                "Build an instance of [`{target_name}`] inside of a [`Box`], consuming the [`{builder_ident}`]{fallible_doc}"
+            );
+            let output = output(quote!(::std::boxed::Box<#target>));
+            let body = convert(quote!(::std::boxed::Box::new));
+            quote!(
+                #[doc = #documentation]
+                #vis fn #ident(self) -> #output {
+                    #body
+                }
+            )
+        });
+
+        let arc = settings.arc.then(|| {
+            let ident = format_ident!("{build_ident}_arc");
+            let documentation = format!(
+                "Build an instance of [`{target_name}`] inside of an [`Arc`](::std::sync::Arc), consuming the [`{builder_ident}`]{fallible_doc}"
+            );
+            let output = output(quote!(::std::sync::Arc<#target>));
+            let body = convert(quote!(::std::sync::Arc::new));
+            quote!(
+                #[doc = #documentation]
+                #vis fn #ident(self) -> #output {
+                    #body
+                }
+            )
+        });
+
+        let into = settings.into.then(|| {
+            let ident = format_ident!("{build_ident}_into");
+            let documentation = format!(
+                "Build an instance of [`{target_name}`] and convert it into any type that implements [`From`] for it, consuming the [`{builder_ident}`]{fallible_doc}"
+            );
+            let output = output(quote!(__T));
+            let body = convert(quote!(::core::convert::From::from));
+            quote!(
+                #[doc = #documentation]
+                #vis fn #ident<__T: ::core::convert::From<#target>>(self) -> #output {
+                    #body
+                }
+            )
+        });
+
+        quote!(
+            #[doc = #documentation]
+            #vis fn #build_ident(self) -> #build_output {
+                #build_body
+            }
+            #boxed
+            #arc
+            #into
+        )
+    }
+
+    /// Generates the code for the setter methods of the builder.
+    fn generate_setters_impl(&self) -> TokenStream {
+        let builder_ident = self.info.builder_ident();
+        let data_field = self.info.data_field_ident();
+        let setters = self
+            .info
+            .field_collection()
+            .iter()
+            .filter(|field| field.kind() != FieldKind::Skipped)
+            .map(|field| {
+                let (const_idents_impl, const_idents_type_input, const_idents_type_output) =
+                    self.const_generic_idents_setter(field);
+                let where_clause = &self.info.generics().where_clause;
+
+                let field_ident = field.ident();
+                let setter_ident = self.info.setter_ident(field);
+                let vis = if self.info.generate_module() {
+                    util::vis_in_module(&self.info.setter_vis(field))
+                } else {
+                    self.info.setter_vis(field)
+                };
+                if field.is_counted() {
+                    return self.generate_counted_each_setters(field, &vis);
+                }
+                // Optional fields of type `Option` take an `Option`, unless a separate setter is generated for that
+                let takes_option = field.is_optional_option() && !field.maybe();
+                let output = quote!(#builder_ident #const_idents_type_output);
+
+                let setter = match field.validate() {
+                    Some(validate) => {
+                        let setter_ident = format_ident!("try_{}", setter_ident);
+                        let input_type = self.field_input_type(field, takes_option);
+                        let value_type = self.field_value_type(field, takes_option);
+                        let input_value = self.field_input_value(field, takes_option);
+                        let assign = self.field_value_assign(field, quote!(#field_ident), takes_option);
+                        let error = field.error().cloned().unwrap_or_else(|| self.info.error_ty());
+                        // The validation function always receives the inner value, and isn't called when an `Option` setter receives `None`
+                        let validation = if takes_option {
+                            quote!(
+                                if let Some(value) = &#field_ident {
+                                    #validate(value)?;
+                                }
+                            )
+                        } else {
+                            quote!(#validate(&#field_ident)?;)
+                        };
+
+                        let documentation = format!(r#"
+Setter for the [`{}::{field_ident}`] field, which validates the value before it's set.
+
+# Arguments
+
+- `{field_ident}`: field to be set
+
+# Returns
+
+`{builder_ident}` with `{field_ident}` initialized, or an error if the validation failed"#, self.info.target_name());
+
+                        quote!(
+                            #[doc = #documentation]
+                            #vis fn #setter_ident (mut self, #field_ident: #input_type) -> ::core::result::Result<#output, #error> {
+                                let #field_ident: #value_type = #input_value;
+                                #validation
+                                self.#data_field.#field_ident = #assign;
+                                ::core::result::Result::Ok(#builder_ident {
+                                    #data_field: self.#data_field,
+                                })
+                            }
+                        )
+                    }
+                    None => self.generate_setter(field, &setter_ident, &vis, takes_option, &output),
+                };
+                let maybe_setter = field.maybe().then(|| {
+                    let setter_ident = format_ident!("maybe_{}", setter_ident);
+                    self.generate_setter(field, &setter_ident, &vis, true, &output)
+                });
+                let propagate_setter =
+                    self.generate_propagate_setter(field, &setter_ident, &vis, takes_option, &output);
+                // The setter for the whole collection is replaced if the setter for each item has the same name
+                let setter = (field.each() != Some(&setter_ident)).then_some(setter);
+                let each_setter = self.generate_each_setter(field, &vis, output);
+
+                quote!(
+                    impl #const_idents_impl #builder_ident #const_idents_type_input #where_clause {
+                        #setter
+                        #maybe_setter
+                        #propagate_setter
+                        #each_setter
+                    }
+                )
+            });
+
+        quote!(
+            #(#setters)*
+        )
+    }
+
+    /// Generates the code for the `clear_{field}` methods, which reset a field to its initial value,
+    /// and reset its const generic to the state in which the field isn't set.
+    fn generate_clears_impl(&self) -> TokenStream {
+        let builder_ident = self.info.builder_ident();
+        let data_field = self.info.data_field_ident();
+        let impl_generics = self.struct_generics();
+        let where_clause = &self.info.generics().where_clause;
+        let type_generics = self.const_generic_idents_type();
+
+        let clears = self
+            .info
+            .field_collection()
+            .iter()
+            .filter(|field| field.kind() != FieldKind::Skipped)
+            .filter_map(|field| {
+                let field_ident = field.ident();
+                let clear_ident = match self.info.clear_ident(field) {
+                    Ok(clear_ident) => clear_ident,
+                    Err(err) => {
+                        emit_error!(err.span(), "{}", err);
+                        return None;
+                    }
+                };
+                let vis = if self.info.generate_module() {
+                    util::vis_in_module(&self.info.setter_vis(field))
+                } else {
+                    self.info.setter_vis(field)
+                };
+                let initial_value = util::data_field_initial_value(field);
+                let output_generics = match TrackedField::new(field) {
+                    Some(field) => {
+                        self.const_generic_idents_set_type(&field, field.const_initial())
+                    }
+                    None => type_generics.clone(),
+                };
+
+                let documentation = format!(
+                    r#"
+Clears the [`{}::{field_ident}`] field, as if it was never set.
+
+# Returns
+
+`{builder_ident}` with `{field_ident}` uninitialized"#,
+                    self.info.target_name()
+                );
+
+                Some(quote!(
+                    #[doc = #documentation]
+                    #vis fn #clear_ident (mut self) -> #builder_ident #output_generics {
+                        self.#data_field.#field_ident = #initial_value;
+                        #builder_ident {
+                            #data_field: self.#data_field,
+                        }
+                    }
+                ))
+            });
+
+        quote!(
+            impl #impl_generics #builder_ident #type_generics #where_clause {
+                #(#clears)*
+            }
+        )
+    }
+
+    /// Generates the code for the `{field}_ref` methods, which retrieve a reference to the value of a field.
+    /// Mandatory and grouped fields only have a getter in the states in which they are set, and it returns a reference to the value.
+    /// All other fields have a getter in every state, which returns an `Option` unless the field is a collection.
+    fn generate_getters_impl(&self) -> TokenStream {
+        let builder_ident = self.info.builder_ident();
+        let impl_generics = self.struct_generics();
+        let where_clause = &self.info.generics().where_clause;
+        let type_generics = self.const_generic_idents_type();
+
+        let (set_fields, other_fields): (Vec<_>, Vec<_>) = self
+            .info
+            .field_collection()
+            .iter()
+            .filter(|field| field.kind() != FieldKind::Skipped)
+            .partition(|field| matches!(field.kind(), FieldKind::Mandatory | FieldKind::Grouped));
+
+        let set_getters = set_fields.into_iter().map(|field| {
+            let const_idents_impl = self.const_generic_idents_set_impl(field);
+            let const_idents_type = self.const_generic_idents_set_type(field, true);
+            let getter = self.generate_getter(field);
+
+            quote!(
+                impl #const_idents_impl #builder_ident #const_idents_type #where_clause {
+                    #getter
+                }
+            )
+        });
+        let other_getters = other_fields
+            .into_iter()
+            .map(|field| self.generate_getter(field));
+
+        quote!(
+            #(#set_getters)*
+            impl #impl_generics #builder_ident #type_generics #where_clause {
+                #(#other_getters)*
+            }
+        )
+    }
+
+    /// Generates the code for the getter of a field, which reads the value from the data struct.
+    ///
+    /// The data struct stores mandatory and grouped fields in an `Option`.
+    /// Their getters are only implemented for the states in which the const generic of the field is `true`,
+    /// so they unwrap the value just like the build method does.
+    fn generate_getter(&self, field: &Field) -> TokenStream {
+        let data_field = self.info.data_field_ident();
+        let field_ident = field.ident();
+        let getter_ident = format_ident!("{}_ref", field_ident);
+        let vis = if self.info.generate_module() {
+            util::vis_in_module(&self.info.setter_vis(field))
+        } else {
+            self.info.setter_vis(field)
+        };
+        let value = quote!(self.#data_field.#field_ident);
+
+        let (output, body) = match field.kind() {
+            FieldKind::Mandatory if field.is_option_type() => {
+                let ty = field.ty();
+                (quote!(&#ty), quote!(&#value))
+            }
+            FieldKind::Mandatory | FieldKind::Grouped => {
+                let ty = self.field_value_type(field, false);
+                (quote!(&#ty), quote!(#value.as_ref().unwrap()))
+            }
+            FieldKind::Optional if field.each().is_some() => {
+                let ty = field.ty();
+                (quote!(&#ty), quote!(&#value))
+            }
+            FieldKind::Optional if field.default().is_some() => {
+                let ty = field.ty();
+                (quote!(Option<&#ty>), quote!(#value.as_ref()))
+            }
+            FieldKind::Optional | FieldKind::Skipped => {
+                let ty = self.field_value_type(field, false);
+                (quote!(Option<&#ty>), quote!(#value.as_ref()))
+            }
+        };
+
+        let documentation = format!(
+            r#"
+Retrieves a reference to the value of the [`{}::{field_ident}`] field.
+
+# Returns
+
+A reference to the value of `{field_ident}`"#,
+            self.info.target_name()
+        );
+
+        quote!(
+            #[doc = #documentation]
+            #vis fn #getter_ident (&self) -> #output {
+                #body
+            }
+        )
+    }
+
+    /// Generates the code for a setter of a field.
+    ///
+    /// # Arguments
+    ///
+    /// - `field`: The field that's set.
+    /// - `setter_ident`: The name of the setter.
+    /// - `vis`: The visibility of the setter.
+    /// - `takes_option`: Whether the setter of a field of type `Option` takes an `Option` instead of the inner type.
+    /// - `output`: The type of the builder that's returned by the setter.
+    fn generate_setter(
+        &self,
+        field: &Field,
+        setter_ident: &syn::Ident,
+        vis: &syn::Visibility,
+        takes_option: bool,
+        output: &TokenStream,
+    ) -> TokenStream {
         let builder_ident = self.info.builder_ident();
-        let target_ident = self.info.ident();
         let data_field = self.info.data_field_ident();
-        let documentation =
-            format!("Build an instance of [`{target_ident}`], consuming the [`{builder_ident}`]");
-
-        let (impl_generics, target_type_generics, where_clause) =
-            self.info.generics().split_for_impl();
-
-        match self.info.solver_kind() {
-            SolverKind::BruteForce => {
-                let build_impls = self.valid_groupident_combinations().map(|group_indices| {
-                    let type_generics = self.const_generic_idents_build(&group_indices);
+        let field_ident = field.ident();
+        let input_type = self.field_input_type(field, takes_option);
+        let input_value = self.field_input_assign(field, takes_option);
 
-                    quote!(
-                        impl #impl_generics #builder_ident #type_generics #where_clause{
-                            #[doc = #documentation]
-                            pub fn build(self) -> #target_ident #target_type_generics {
-                                self.#data_field.into()
-                            }
-                        }
-                    )
-                });
+        let documentation = format!(
+            r#"
+Setter for the [`{}::{field_ident}`] field.
 
-                quote!(
-                    #(#build_impls)*
-                )
-            }
-            SolverKind::Compiler => {
-                let builder_ident = self.info.builder_ident();
-                let impl_generics = self.const_generic_group_partial_idents();
-                let type_generics = self.const_generic_idents_build_unset_group();
+# Arguments
 
-                let correctness_verifier = self.impl_correctness_verifier();
-                let correctness_check = self.impl_correctness_check();
-                let correctness_helper_fns = self.impl_correctness_helper_fns();
+- `{field_ident}`: field to be set
 
-                let target_ident = self.info.ident();
-                let (_, target_type_generics, where_clause) = self.info.generics().split_for_impl();
+# Returns
 
-                quote!(
-                    impl #impl_generics #builder_ident #type_generics #where_clause{
-                        #correctness_verifier
-                        #correctness_helper_fns
+`{builder_ident}` with `{field_ident}` initialized"#,
+            self.info.target_name()
+        );
 
-                        #[doc = #documentation]
-                        pub fn build(self) -> #target_ident #target_type_generics {
-                            #correctness_check
-                            self.#data_field.into()
-                        }
-                    }
-                )
+        quote!(
+            #[doc = #documentation]
+            #vis fn #setter_ident (mut self, #field_ident: #input_type) -> #output {
+                self.#data_field.#field_ident = #input_value;
+                #builder_ident {
+                    #data_field: self.#data_field,
+                }
             }
-        }
+        )
     }
 
-    /// Generates the code for the setter methods of the builder.
-    fn generate_setters_impl(&self) -> TokenStream {
+    /// Generates the code for the setter that propagates the construction of a field to its builder, if the field has one.
+    /// The setter builds the builder that's returned by the closure, and passes the value to the normal setter of the field.
+    ///
+    /// # Arguments
+    ///
+    /// - `field`: The field that's set.
+    /// - `setter_ident`: The name of the normal setter, which is extended with the suffix.
+    /// - `vis`: The visibility of the setter.
+    /// - `takes_option`: Whether the normal setter of a field of type `Option` takes an `Option` instead of the inner type.
+    /// - `output`: The type of the builder that's returned by the setter.
+    fn generate_propagate_setter(
+        &self,
+        field: &Field,
+        setter_ident: &syn::Ident,
+        vis: &syn::Visibility,
+        takes_option: bool,
+        output: &TokenStream,
+    ) -> Option<TokenStream> {
+        let suffix = field.propagate()?;
         let builder_ident = self.info.builder_ident();
-        let data_field = self.info.data_field_ident();
-        let setters = self
-            .info
-            .field_collection()
-            .iter()
-            .filter(|field| field.kind() != FieldKind::Skipped)
-            .map(|field| {
-                let const_idents_impl = self.const_generic_idents_set_impl(field);
-                let const_idents_type_input = self.const_generic_idents_set_type(field, false);
-                let const_idents_type_output = self.const_generic_idents_set_type(field, true);
-                let where_clause = &self.info.generics().where_clause;
+        let field_ident = field.ident();
+        let ty = self.field_value_type(field, false);
 
-                let field_ident = field.ident();
-                let input_type = self.field_input_type(field);
-                let input_value = self.field_input_assign(field);
+        let built = quote!(::const_typed_builder::Buildable::build(#field_ident(<#ty as Builder>::builder())));
+        let value = if takes_option {
+            quote!(Some(value))
+        } else {
+            quote!(value)
+        };
+        let (propagate_ident, setter_ident, output, fallible_doc) = match field.validate() {
+            Some(_) => {
+                let error = field
+                    .error()
+                    .cloned()
+                    .unwrap_or_else(|| self.info.error_ty());
+                (
+                    format_ident!("try_{}_{}", setter_ident, suffix),
+                    format_ident!("try_{}", setter_ident),
+                    quote!(::core::result::Result<#output, #error>),
+                    ", or an error if the validation failed",
+                )
+            }
+            None => (
+                format_ident!("{}_{}", setter_ident, suffix),
+                setter_ident.clone(),
+                output.clone(),
+                "",
+            ),
+        };
 
-                let documentation = format!(r#"
-Setter for the [`{}::{field_ident}`] field.
+        let documentation = format!(
+            r#"
+Setter for the [`{}::{field_ident}`] field, which propagates the construction of the field to its builder.
 
 # Arguments
 
-- `{field_ident}`: field to be set
+- `{field_ident}`: closure that receives the builder of the field, and returns it in a state in which it can be built
 
 # Returns
 
-`{builder_ident}` with `{field_ident}` initialized"#, self.info.ident());
+`{builder_ident}` with `{field_ident}` initialized{fallible_doc}.
+If the builder of the field is validated, this is wrapped in a `Result` with the error of its validation"#,
+            self.info.target_name()
+        );
+
+        Some(quote!(
+            #[doc = #documentation]
+            #vis fn #propagate_ident<__B>(self, #field_ident: impl FnOnce(<#ty as Builder>::BuilderImpl) -> __B) -> <__B::Target as ::const_typed_builder::Propagated<#ty>>::Output<#output>
+            where
+                __B: ::const_typed_builder::Buildable,
+                __B::Target: ::const_typed_builder::Propagated<#ty>,
+            {
+                ::const_typed_builder::Propagated::map(#built, |value| self.#setter_ident(#value))
+            }
+        ))
+    }
+
+    /// Generates the code for the setters that add a single item to a collection, of which the number of items is tracked.
+    /// A setter is generated for every tracked count, which increments the count unless it's saturated at the minimum.
+    /// No setters are generated for the count that's equal to the maximum, so no more items can be added.
+    fn generate_counted_each_setters(&self, field: &Field, vis: &syn::Visibility) -> TokenStream {
+        let builder_ident = self.info.builder_ident();
+        let const_idents_impl = self.const_generic_idents_set_impl(field);
+        let where_clause = &self.info.generics().where_clause;
+
+        let setters = (0..=field.count_cap())
+            .filter(|count| field.can_add_item(*count))
+            .map(|count| {
+                let next = (count + 1).min(field.count_cap());
+                let const_idents_type_input =
+                    self.const_generic_idents_set_type(field, Literal::usize_unsuffixed(count));
+                let const_idents_type_output =
+                    self.const_generic_idents_set_type(field, Literal::usize_unsuffixed(next));
+                let each_setter = self.generate_each_setter(
+                    field,
+                    vis,
+                    quote!(#builder_ident #const_idents_type_output),
+                );
 
                 quote!(
                     impl #const_idents_impl #builder_ident #const_idents_type_input #where_clause {
-                        #[doc = #documentation]
-                        pub fn #field_ident (mut self, #field_ident: #input_type) -> #builder_ident #const_idents_type_output {
-                            self.#data_field.#field_ident = #input_value;
-                            #builder_ident {
-                                #data_field: self.#data_field,
-                            }
-                        }
+                        #each_setter
                     }
                 )
             });
@@ -220,17 +1155,74 @@ Setter for the [`{}::{field_ident}`] field.
         )
     }
 
+    /// Generates the code for the setter that adds a single item to a collection, if the field has one.
+    ///
+    /// # Arguments
+    ///
+    /// - `field`: The field that contains the collection.
+    /// - `vis`: The visibility of the setter.
+    /// - `output`: The type of the builder that's returned by the setter.
+    fn generate_each_setter(
+        &self,
+        field: &Field,
+        vis: &syn::Visibility,
+        output: TokenStream,
+    ) -> Option<TokenStream> {
+        let each_ident = field.each()?;
+        let field_ident = field.ident();
+        let builder_ident = self.info.builder_ident();
+        let data_field = self.info.data_field_ident();
+        let ty = field.ty();
+        let item_type = quote!(<#ty as ::core::iter::IntoIterator>::Item);
+        let (input_type, input_value) = match field.setter_kind() {
+            SetterKind::Into => (
+                quote!(impl Into<#item_type>),
+                quote!(Into::<#item_type>::into(#each_ident)),
+            ),
+            _ => (item_type, quote!(#each_ident)),
+        };
+
+        let documentation = format!(
+            r#"
+Adds a single item to the [`{}::{field_ident}`] field.
+
+# Arguments
+
+- `{each_ident}`: item to be added
+
+# Returns
+
+`{}` with the item added to `{field_ident}`"#,
+            self.info.target_name(),
+            builder_ident
+        );
+
+        Some(quote!(
+            #[doc = #documentation]
+            #vis fn #each_ident (mut self, #each_ident: #input_type) -> #output {
+                ::core::iter::Extend::extend(&mut self.#data_field.#field_ident, ::core::iter::once(#input_value));
+                #builder_ident {
+                    #data_field: self.#data_field,
+                }
+            }
+        ))
+    }
+
     fn struct_generics(&self) -> syn::Generics {
         let mut all = self
             .info
             .field_collection()
             .iter()
             .filter_map(TrackedField::new)
-            .map(|field| field.const_ident());
+            .map(|field| field.const_param());
         self.add_const_generics_for_impl(&mut all)
     }
 
-    fn const_generic_idents_build(&self, true_indices: &[usize]) -> TokenStream {
+    fn const_generic_idents_build(
+        &self,
+        true_indices: &[usize],
+        counts: &[(usize, usize)],
+    ) -> TokenStream {
         let mut all = self
             .info
             .field_collection()
@@ -242,6 +1234,32 @@ Setter for the [`{}::{field_ident}`] field.
                     quote!(true)
                 }
                 TrackedFieldKind::Grouped => quote!(false),
+                TrackedFieldKind::Counted => Self::const_count(&field, counts),
+                TrackedFieldKind::Optional => field.const_ident().to_token_stream(),
+            });
+        util::add_const_valued_generics_for_type(&mut all, self.info.generics())
+    }
+
+    /// Generates the const generics of the state of the builder that's converted from the target, in which every field is set.
+    /// Grouped fields are left generic if no indices of set grouped fields are given.
+    fn const_generic_idents_from(
+        &self,
+        true_indices: Option<&[usize]>,
+        counts: &[(usize, usize)],
+    ) -> TokenStream {
+        let mut all = self
+            .info
+            .field_collection()
+            .iter()
+            .filter_map(TrackedField::new)
+            .map(|field| match field.kind() {
+                TrackedFieldKind::Mandatory | TrackedFieldKind::Optional => quote!(true),
+                TrackedFieldKind::Grouped => match true_indices {
+                    Some(true_indices) if true_indices.contains(&field.index()) => quote!(true),
+                    Some(_) => quote!(false),
+                    None => field.const_ident().to_token_stream(),
+                },
+                TrackedFieldKind::Counted => Self::const_count(&field, counts),
             });
         util::add_const_valued_generics_for_type(&mut all, self.info.generics())
     }
@@ -256,13 +1274,66 @@ Setter for the [`{}::{field_ident}`] field.
                 if field.deref() == field_info {
                     None
                 } else {
-                    Some(field.const_ident())
+                    Some(field.const_param())
                 }
             });
         self.add_const_generics_for_impl(&mut all)
     }
 
-    fn const_generic_idents_set_type(&self, field_info: &Field, value: bool) -> TokenStream {
+    /// Generates the const generics of the impl of the setters of a field, and of the builder before and after the field is set.
+    /// The fields that conflict with the field have to be unset, so its setters aren't available once one of them is set.
+    fn const_generic_idents_setter(
+        &self,
+        field_info: &Field,
+    ) -> (syn::Generics, TokenStream, TokenStream) {
+        let conflicting_indices: BTreeSet<usize> =
+            self.info.conflicting_indices(field_info.index()).collect();
+        let mut params = self
+            .info
+            .field_collection()
+            .iter()
+            .filter_map(TrackedField::new)
+            .filter(|field| {
+                field.deref() != field_info && !conflicting_indices.contains(&field.index())
+            })
+            .map(|field| field.const_param());
+        let impl_generics = self.add_const_generics_for_impl(&mut params);
+
+        let type_generics = |value: bool| {
+            let mut all = self
+                .info
+                .field_collection()
+                .iter()
+                .filter_map(TrackedField::new)
+                .map(|field| {
+                    if field.deref() == field_info {
+                        quote!(#value)
+                    } else if conflicting_indices.contains(&field.index()) {
+                        quote!(false)
+                    } else {
+                        field.const_ident().to_token_stream()
+                    }
+                });
+            util::add_const_valued_generics_for_type(&mut all, self.info.generics())
+        };
+        (impl_generics, type_generics(false), type_generics(true))
+    }
+
+    fn const_generic_idents_type(&self) -> TokenStream {
+        let mut all = self
+            .info
+            .field_collection()
+            .iter()
+            .filter_map(TrackedField::new)
+            .map(|field| field.const_ident().to_token_stream());
+        util::add_const_valued_generics_for_type(&mut all, self.info.generics())
+    }
+
+    fn const_generic_idents_set_type(
+        &self,
+        field_info: &Field,
+        value: impl ToTokens,
+    ) -> TokenStream {
         let mut all = self
             .info
             .field_collection()
@@ -284,14 +1355,31 @@ Setter for the [`{}::{field_ident}`] field.
             .info
             .field_collection()
             .iter()
+            .filter_map(TrackedField::new)
+            .filter_map(|field| match field.kind() {
+                TrackedFieldKind::Grouped | TrackedFieldKind::Optional => Some(field.const_param()),
+                TrackedFieldKind::Mandatory | TrackedFieldKind::Counted => None,
+            });
+        self.add_const_generics_for_impl(&mut all)
+    }
+
+    /// Generates the generics of an impl that's generic over the state of the grouped fields.
+    fn const_generic_group_params(&self) -> syn::Generics {
+        let mut all = self
+            .info
+            .field_collection()
+            .iter()
+            .filter_map(TrackedField::new)
             .filter_map(|field| match field.kind() {
-                FieldKind::Grouped => Some(field.const_ident()),
-                FieldKind::Optional | FieldKind::Skipped | FieldKind::Mandatory => None,
+                TrackedFieldKind::Grouped => Some(field.const_param()),
+                TrackedFieldKind::Mandatory
+                | TrackedFieldKind::Counted
+                | TrackedFieldKind::Optional => None,
             });
         self.add_const_generics_for_impl(&mut all)
     }
 
-    fn const_generic_idents_build_unset_group(&self) -> TokenStream {
+    fn const_generic_idents_build_unset_group(&self, counts: &[(usize, usize)]) -> TokenStream {
         let mut all = self
             .info
             .field_collection()
@@ -303,39 +1391,142 @@ Setter for the [`{}::{field_ident}`] field.
                     let ident = field.const_ident();
                     quote!(#ident)
                 }
+                TrackedFieldKind::Counted => Self::const_count(&field, counts),
+                TrackedFieldKind::Optional => field.const_ident().to_token_stream(),
             });
         util::add_const_valued_generics_for_type(&mut all, self.info.generics())
     }
 
+    /// Generates the generics of the build impls of the brute force solver, in which optional fields that can only be set once
+    /// can be in any state.
+    fn const_generic_optional_params(&self) -> syn::Generics {
+        let mut all = self
+            .info
+            .field_collection()
+            .iter()
+            .filter_map(TrackedField::new)
+            .filter_map(|field| match field.kind() {
+                TrackedFieldKind::Optional => Some(field.const_param()),
+                TrackedFieldKind::Mandatory
+                | TrackedFieldKind::Grouped
+                | TrackedFieldKind::Counted => None,
+            });
+        self.add_const_generics_for_impl(&mut all)
+    }
+
+    /// Retrieves the value of the const generic of a counted collection in a combination of counts.
+    fn const_count(field: &Field, counts: &[(usize, usize)]) -> TokenStream {
+        let count = counts
+            .iter()
+            .find_map(|(index, count)| (*index == field.index()).then_some(*count))
+            .expect("Every counted field has a count in the combination");
+        Literal::usize_unsuffixed(count).to_token_stream()
+    }
+
     fn impl_correctness_verifier(&self) -> TokenStream {
-        if self.info.group_collection().is_empty() {
+        if self.info.group_collection().is_empty() && self.info.constraint_collection().is_empty() {
             return TokenStream::new();
         }
 
+        let build_ident = self.info.build_fn_ident();
         let all = self.info.group_collection().values().map(|group| {
-            let partials = group.indices().iter().map(|index| self.info.field_collection().get(*index).expect("Could not find field associated to group").const_ident());
-            let function_call = syn::Ident::new(group.function_symbol().as_ref(), Span::call_site());
-            let count = group.expected_count();
+            let group_call = self.group_verifier_call(group);
             let ident = group.ident();
-            let function_name = group.function_symbol();
-            let err_text = format!("`.build()` failed because the bounds of group `{ident}` where not met ({function_name} {count})");
+            let group_type = group.group_type();
+            let err_text = format!("`.{build_ident}()` failed because the bounds of group `{ident}` where not met ({group_type})");
 
             quote!(
-                if !Self::#function_call(&[#(#partials),*], #count) {
+                if !#group_call {
+                    panic!(#err_text);
+                }
+            )
+        });
+        let constraints = self.info.constraint_collection().iter().map(|constraint| {
+            let condition = self.constraint_verifier_expression(constraint.expr());
+            let err_text = format!(
+                "`.{build_ident}()` failed because the constraint {constraint} was not met"
+            );
+
+            quote!(
+                if !#condition {
                     panic!(#err_text);
                 }
             )
         });
         quote!(
-            const GROUP_VERIFIER: ()  = {
+            const GROUP_VERIFIER: () = {
                 #(#all)*
-                ()
+                #(#constraints)*
             };
         )
     }
 
+    /// Generates the conditions that check the groups and constraints in the compiler solver, which are true if the builder can be built.
+    fn compiler_conditions(&self) -> Vec<TokenStream> {
+        self.info
+            .group_collection()
+            .values()
+            .map(|group| self.group_verifier_call(group))
+            .chain(
+                self.info
+                    .constraint_collection()
+                    .iter()
+                    .map(|constraint| self.constraint_verifier_expression(constraint.expr())),
+            )
+            .collect()
+    }
+
+    /// Generates the call to the const helper function that checks a group in the compiler solver.
+    fn group_verifier_call(&self, group: &Group) -> TokenStream {
+        let partials = group.indices().iter().map(|index| {
+            self.info
+                .field_collection()
+                .get(*index)
+                .expect("Could not find field associated to group")
+                .const_ident()
+        });
+        let function_call = syn::Ident::new(group.function_symbol().as_ref(), Span::call_site());
+        let arguments = match *group.group_type() {
+            GroupType::Exact(count) | GroupType::AtLeast(count) | GroupType::AtMost(count) => {
+                quote!(, #count)
+            }
+            GroupType::Between(min, max) => quote!(, #min, #max),
+            GroupType::AllOrNone => TokenStream::new(),
+        };
+        quote!(Self::#function_call(&[#(#partials),*] #arguments))
+    }
+
+    /// Generates the const expression that checks a constraint in the compiler solver.
+    fn constraint_verifier_expression(&self, expression: &Expression) -> TokenStream {
+        match expression {
+            Expression::Field(_, index) => self
+                .info
+                .field_collection()
+                .get(*index)
+                .expect("Could not find field associated to constraint")
+                .const_ident()
+                .to_token_stream(),
+            Expression::Not(inner) => {
+                let inner = self.constraint_verifier_expression(inner);
+                quote!(!#inner)
+            }
+            Expression::All(operands) => {
+                let operands = operands
+                    .iter()
+                    .map(|operand| self.constraint_verifier_expression(operand));
+                quote!((#(#operands)&&*))
+            }
+            Expression::Any(operands) => {
+                let operands = operands
+                    .iter()
+                    .map(|operand| self.constraint_verifier_expression(operand));
+                quote!((#(#operands)||*))
+            }
+        }
+    }
+
     fn impl_correctness_check(&self) -> TokenStream {
-        if self.info.group_collection().is_empty() {
+        if self.info.group_collection().is_empty() && self.info.constraint_collection().is_empty() {
             TokenStream::new()
         } else {
             quote!(let _ = Self::GROUP_VERIFIER;)
@@ -350,15 +1541,19 @@ Setter for the [`{}::{field_ident}`] field.
         let mut exact = false;
         let mut at_least = false;
         let mut at_most = false;
+        let mut between = false;
+        let mut all_or_none = false;
 
         for group in self.info.group_collection().values() {
             match group.group_type() {
                 GroupType::Exact(_) => exact = true,
                 GroupType::AtLeast(_) => at_least = true,
                 GroupType::AtMost(_) => at_most = true,
+                GroupType::Between(_, _) => between = true,
+                GroupType::AllOrNone => all_or_none = true,
             }
 
-            if exact && at_least && at_most {
+            if exact && at_least && at_most && between && all_or_none {
                 break;
             }
         }
@@ -410,61 +1605,74 @@ Setter for the [`{}::{field_ident}`] field.
                 }
             )
         });
+
+        let between = between.then(|| {
+            quote!(
+                const fn between(input: &[bool], min: usize, max: usize) -> bool {
+                    let mut this_count = 0;
+                    let mut i = 0;
+                    while i < input.len() {
+                        if input[i] {
+                            this_count += 1
+                        }
+                        i += 1;
+                    }
+                    min <= this_count && this_count <= max
+                }
+            )
+        });
+
+        let all_or_none = all_or_none.then(|| {
+            quote!(
+                const fn all_or_none(input: &[bool]) -> bool {
+                    let mut this_count = 0;
+                    let mut i = 0;
+                    while i < input.len() {
+                        if input[i] {
+                            this_count += 1
+                        }
+                        i += 1;
+                    }
+                    this_count == 0 || this_count == input.len()
+                }
+            )
+        });
         quote!(
             #exact
             #at_least
             #at_most
+            #between
+            #all_or_none
         )
     }
 
     /// Retrieves the input type for the builder's setter method.
-    pub fn field_input_type(&self, field: &Field) -> Option<TokenStream> {
+    ///
+    /// # Arguments
+    ///
+    /// - `field`: The field that's set.
+    /// - `takes_option`: Whether the setter of a field of type `Option` takes an `Option` instead of the inner type.
+    pub fn field_input_type(&self, field: &Field, takes_option: bool) -> Option<TokenStream> {
         if field.kind() == FieldKind::Skipped {
             return None;
         }
 
+        let ty = if let Some(inner_ty) = field.inner_type() {
+            inner_ty
+        } else {
+            field.ty()
+        };
+
         let input_type = match field.setter_kind() {
-            SetterKind::Standard => match field.kind() {
-                FieldKind::Grouped | FieldKind::Mandatory if field.is_option_type() => field
-                    .inner_type()
-                    .expect("Option types have an inner type")
-                    .to_token_stream(),
-                FieldKind::Skipped => unreachable!("Skipped fields have an early return"),
-                _ => field.ty().to_token_stream(),
-            },
-            SetterKind::Propagate => {
-                let input = if let Some(inner_ty) = field.inner_type() {
-                    inner_ty
-                } else {
-                    field.ty()
-                };
-                let output = match field.kind() {
-                    FieldKind::Grouped | FieldKind::Mandatory if field.is_option_type() => {
-                        field.inner_type().expect("Option types have an inner type")
-                    }
-                    FieldKind::Skipped => unreachable!("Skipped fields have an early return"),
-                    _ => field.ty(),
-                };
-                quote!(fn(<#input as Builder>:: BuilderImpl) -> #output)
-            }
+            SetterKind::Standard => self.field_value_type(field, takes_option).to_token_stream(),
             SetterKind::Into => {
-                let ty = if let Some(inner_ty) = field.inner_type() {
-                    inner_ty
-                } else {
-                    field.ty()
-                };
-                if field.kind() == FieldKind::Optional {
+                if takes_option {
                     quote!(Option<impl Into<#ty>>)
                 } else {
                     quote!(impl Into<#ty>)
                 }
             }
             SetterKind::AsMut => {
-                let ty = if let Some(inner_ty) = field.inner_type() {
-                    inner_ty
-                } else {
-                    field.ty()
-                };
                 if let syn::Type::Reference(syn::TypeReference {
                     lifetime,
                     mutability,
@@ -478,7 +1686,7 @@ Setter for the [`{}::{field_ident}`] field.
                             "You need a mutable reference to use this type of setter"
                         );
                     }
-                    if field.kind() == FieldKind::Optional {
+                    if takes_option {
                         quote!(Option<&#lifetime mut impl AsMut<#elem>>)
                     } else {
                         quote!(&#lifetime mut impl AsMut<#elem>)
@@ -492,11 +1700,6 @@ Setter for the [`{}::{field_ident}`] field.
                 }
             }
             SetterKind::AsRef => {
-                let ty = if let Some(inner_ty) = field.inner_type() {
-                    inner_ty
-                } else {
-                    field.ty()
-                };
                 if let syn::Type::Reference(syn::TypeReference {
                     lifetime,
                     mutability: _,
@@ -504,7 +1707,7 @@ Setter for the [`{}::{field_ident}`] field.
                     ..
                 }) = ty
                 {
-                    if field.kind() == FieldKind::Optional {
+                    if takes_option {
                         quote!(Option<&#lifetime impl AsRef<#elem>>)
                     } else {
                         quote!(&#lifetime impl AsRef<#elem>)
@@ -518,7 +1721,9 @@ Setter for the [`{}::{field_ident}`] field.
         Some(input_type)
     }
 
-    pub fn field_input_assign(&self, field: &Field) -> Option<TokenStream> {
+    /// Retrieves the expression that converts the input of the setter into the value of the field.
+    /// For fields of type `Option`, this is the value inside of the `Option` unless the setter takes an `Option`.
+    pub fn field_input_value(&self, field: &Field, takes_option: bool) -> Option<TokenStream> {
         if field.kind() == FieldKind::Skipped {
             return None;
         }
@@ -526,45 +1731,80 @@ Setter for the [`{}::{field_ident}`] field.
         let field_ident = field.ident();
 
         let field_value = match field.setter_kind() {
-            SetterKind::Standard => {
-                if field.kind() == FieldKind::Optional {
-                    quote!(#field_ident)
-                } else {
-                    quote!(Some(#field_ident))
-                }
-            }
-            SetterKind::Propagate => {
-                if let Some(inner_ty) = field.inner_type() {
-                    quote!(#field_ident(<#inner_ty as Builder>::builder()))
-                } else {
-                    let ty = field.ty();
-                    quote!(Some(#field_ident(<#ty as Builder>::builder())))
-                }
-            }
+            SetterKind::Standard => quote!(#field_ident),
             SetterKind::Into => {
-                if field.kind() == FieldKind::Optional {
+                if takes_option {
                     quote!(#field_ident.map(Into::into))
                 } else {
-                    quote!(Some(#field_ident.into()))
+                    quote!(#field_ident.into())
                 }
             }
             SetterKind::AsMut => {
-                if field.kind() == FieldKind::Optional {
+                if takes_option {
                     quote!(#field_ident.map(AsMut::as_mut))
                 } else {
-                    quote!(Some(#field_ident.as_mut()))
+                    quote!(#field_ident.as_mut())
                 }
             }
             SetterKind::AsRef => {
-                if field.kind() == FieldKind::Optional {
+                if takes_option {
                     quote!(#field_ident.map(AsRef::as_ref))
                 } else {
-                    quote!(Some(#field_ident.as_ref()))
+                    quote!(#field_ident.as_ref())
                 }
             }
         };
 
-        Some(quote!(#field_value))
+        Some(field_value)
+    }
+
+    /// Retrieves the type of the value returned by [`field_input_value`].
+    pub fn field_value_type<'a>(&self, field: &'a Field, takes_option: bool) -> &'a syn::Type {
+        match field.inner_type() {
+            Some(inner_ty) if !takes_option => inner_ty,
+            _ => field.ty(),
+        }
+    }
+
+    /// Wraps the value of a field in the way it's stored in the data struct.
+    pub fn field_value_assign(
+        &self,
+        field: &Field,
+        value: TokenStream,
+        takes_option: bool,
+    ) -> TokenStream {
+        if takes_option || field.each().is_some() {
+            value
+        } else {
+            quote!(Some(#value))
+        }
+    }
+
+    /// Retrieves the expression that converts the input of the setter into the value stored in the data struct.
+    pub fn field_input_assign(&self, field: &Field, takes_option: bool) -> Option<TokenStream> {
+        self.field_input_value(field, takes_option)
+            .map(|value| self.field_value_assign(field, value, takes_option))
+    }
+
+    /// Retrieves all combinations of counts of the counted collections that satisfy their bounds,
+    /// as pairs of the index of the field and the count.
+    fn valid_count_combinations(&self) -> Vec<Vec<(usize, usize)>> {
+        self.info
+            .field_collection()
+            .iter()
+            .filter(|field| field.is_counted())
+            .fold(vec![Vec::new()], |combinations, field| {
+                combinations
+                    .into_iter()
+                    .flat_map(|combination| {
+                        field.valid_counts().map(move |count| {
+                            let mut combination = combination.clone();
+                            combination.push((field.index(), count));
+                            combination
+                        })
+                    })
+                    .collect()
+            })
     }
 
     fn valid_groupident_combinations(&self) -> impl Iterator<Item = Vec<usize>> + '_ {
@@ -573,6 +1813,12 @@ Setter for the [`{}::{field_ident}`] field.
             .group_collection()
             .values()
             .flat_map(|group| group.indices().clone())
+            .chain(
+                self.info
+                    .constraint_collection()
+                    .iter()
+                    .flat_map(Constraint::indices),
+            )
             .collect();
         let powerset: Powerset<std::collections::btree_set::IntoIter<usize>> =
             group_indices.into_iter().powerset();
@@ -582,6 +1828,11 @@ Setter for the [`{}::{field_ident}`] field.
                 .group_collection()
                 .values()
                 .all(|group| group.is_valid_with(&set))
+                && self
+                    .info
+                    .constraint_collection()
+                    .iter()
+                    .all(|constraint| constraint.is_valid_with(&set))
             {
                 Some(set)
             } else {
@@ -597,11 +1848,10 @@ Setter for the [`{}::{field_ident}`] field.
     /// A `syn::Generics` instance representing the generics for the builder struct.
     fn add_const_generics_for_impl(
         &self,
-        tokens: &mut impl Iterator<Item = syn::Ident>,
+        params: &mut impl Iterator<Item = GenericParam>,
     ) -> syn::Generics {
         let mut res = self.info.generics().clone();
-        res.params
-            .extend(tokens.map::<GenericParam, _>(|token| parse_quote!(const #token: bool)));
+        res.params.extend(params);
         res
     }
 }
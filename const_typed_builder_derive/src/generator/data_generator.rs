@@ -1,3 +1,4 @@
+use super::util;
 use crate::info::{Container, FieldKind};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
@@ -43,6 +44,7 @@ impl<'a> DataGenerator<'a> {
     fn generate_impl(&self) -> TokenStream {
         let data_ident = self.info.data_ident();
         let struct_ident = self.info.ident();
+        let target_path = self.info.target_path();
         let from_fields = self.impl_from_fields();
         let def_fields = self.impl_default_fields();
 
@@ -52,7 +54,7 @@ impl<'a> DataGenerator<'a> {
             impl #impl_generics From<#data_ident #type_generics> for #struct_ident #type_generics #where_clause {
                 #[doc(hidden)]
                 fn from(data: #data_ident #type_generics) -> #struct_ident #type_generics {
-                    #struct_ident {
+                    #target_path {
                         #(#from_fields),*
                     }
                 }
@@ -73,25 +75,39 @@ impl<'a> DataGenerator<'a> {
     /// Generates the code for the data struct itself.
     fn generate_struct(&self) -> TokenStream {
         let data_ident = self.info.data_ident();
+        let vis = self.vis();
 
         let fields = self.struct_fields();
+        let phantom_field = self.phantom_field();
         let (impl_generics, _type_generics, where_clause) = self.info.generics().split_for_impl();
 
         let tokens = quote!(
             #[doc(hidden)]
-            pub struct #data_ident #impl_generics #where_clause{
-                #(#fields),*
+            #vis struct #data_ident #impl_generics #where_clause{
+                #(#fields,)*
+                #phantom_field
             }
         );
         tokens
     }
 
+    /// Retrieves the visibility of the data struct and its fields, which is the visibility of the builder.
+    /// The data struct is never more visible than the builder, so the type state of the builder can't be bypassed through it.
+    fn vis(&self) -> syn::Visibility {
+        if self.info.generate_module() {
+            util::vis_in_module(self.info.builder_vis())
+        } else {
+            self.info.builder_vis().clone()
+        }
+    }
+
     /// Generates code for the fields of the data struct and returns a token stream.
     ///
     /// # Returns
     ///
-    /// A `Vec<TokenStream>` representing the data struct fields: `pub field_ident: field_type`.
+    /// A `Vec<TokenStream>` representing the data struct fields: `vis field_ident: field_type`.
     fn struct_fields(&self) -> Vec<TokenStream> {
+        let vis = self.vis();
         self.info
             .field_collection()
             .iter()
@@ -100,6 +116,11 @@ impl<'a> DataGenerator<'a> {
 
                 let data_field_type = match field.kind() {
                     FieldKind::Skipped => return None,
+                    FieldKind::Optional if field.each().is_some() => field.ty().to_token_stream(),
+                    FieldKind::Optional if field.default().is_some() => {
+                        let ty = field.ty();
+                        quote!(Option<#ty>)
+                    }
                     FieldKind::Optional => field.ty().to_token_stream(),
                     FieldKind::Mandatory if field.is_option_type() => field.ty().to_token_stream(),
                     FieldKind::Mandatory => {
@@ -110,34 +131,62 @@ impl<'a> DataGenerator<'a> {
                 };
 
                 let tokens = quote!(
-                    pub #field_ident: #data_field_type
+                    #vis #field_ident: #data_field_type
                 );
                 Some(tokens)
             })
             .collect()
     }
 
+    /// Generates a marker field for the generics of the target, as the fields of an enum variant don't necessarily use all of them.
+    ///
+    /// # Returns
+    ///
+    /// An optional `TokenStream` representing the marker field, which is only generated if the target has generics.
+    fn phantom_field(&self) -> Option<TokenStream> {
+        if self.info.generics().params.is_empty() {
+            return None;
+        }
+        let struct_ident = self.info.ident();
+        let (_, type_generics, _) = self.info.generics().split_for_impl();
+        let vis = self.vis();
+        Some(quote!(
+            #vis __phantom: ::core::marker::PhantomData<fn() -> #struct_ident #type_generics>
+        ))
+    }
+
     // Generates code for the `From` trait implementation for converting data struct fields to target struct fields and returns a token stream.
     ///
     /// # Returns
     ///
-    /// A `Vec<TokenStream>` representing the fields for the `From` trait implementation. Either containing `unwrap`, `None` or just the type.
+    /// A `Vec<TokenStream>` representing the fields for the `From` trait implementation. Either containing `unwrap`, `unwrap_or_else`, `None` or just the type.
     fn impl_from_fields(&self) -> Vec<TokenStream> {
         self.info
             .field_collection()
             .iter()
             .map(|field| {
                 let field_ident = field.ident();
+                let member = field.member();
                 match field.kind() {
-                    FieldKind::Skipped => quote!(#field_ident: None),
+                    FieldKind::Skipped => {
+                        let default = field.default();
+                        quote!(#member: #default)
+                    }
                     FieldKind::Mandatory if field.is_option_type() => {
-                        quote!(#field_ident: data.#field_ident)
+                        quote!(#member: data.#field_ident)
+                    }
+                    FieldKind::Optional if field.each().is_some() => {
+                        quote!(#member: data.#field_ident)
+                    }
+                    FieldKind::Optional if field.default().is_some() => {
+                        let default = field.default();
+                        quote!(#member: data.#field_ident.unwrap_or_else(|| #default))
                     }
                     FieldKind::Optional | FieldKind::Grouped => {
-                        quote!(#field_ident: data.#field_ident)
+                        quote!(#member: data.#field_ident)
                     }
                     FieldKind::Mandatory => {
-                        quote!(#field_ident: data.#field_ident.unwrap())
+                        quote!(#member: data.#field_ident.unwrap())
                     }
                 }
             })
@@ -150,17 +199,22 @@ impl<'a> DataGenerator<'a> {
     ///
     /// A `TokenStream` representing the generated default field values.
     fn impl_default_fields(&self) -> TokenStream {
-        let fields_none = self
+        let phantom = self
+            .phantom_field()
+            .map(|_| quote!(__phantom: ::core::marker::PhantomData));
+        let fields_default = self
             .info
             .field_collection()
             .iter()
             .filter(|field| field.kind() != FieldKind::Skipped)
             .map(|field| {
                 let field_ident = field.ident();
-                quote!(#field_ident: None)
+                let initial_value = util::data_field_initial_value(field);
+                quote!(#field_ident: #initial_value)
             });
         quote!(
-            #(#fields_none),*
+            #(#fields_default,)*
+            #phantom
         )
     }
 }
@@ -11,10 +11,10 @@ use target_generator::TargetGenerator;
 
 /// The `Generator` struct is responsible for generating code for the builder pattern based on the provided `StructInfo`.
 pub struct Generator<'info> {
-    info: &'info Container<'info>,
-    data_gen: DataGenerator<'info>,
+    infos: &'info [Container<'info>],
+    data_gens: Vec<DataGenerator<'info>>,
     target_gen: TargetGenerator<'info>,
-    builder_gen: BuilderGenerator<'info>,
+    builder_gens: Vec<BuilderGenerator<'info>>,
 }
 
 impl<'info> Generator<'info> {
@@ -22,21 +22,27 @@ impl<'info> Generator<'info> {
     ///
     /// # Arguments
     ///
-    /// - `info`: The `Container` containing all the information of the data container.
+    /// - `infos`: The `Container`s containing all the information of the data container.
+    ///   Structs have exactly one container, enums have one container per variant.
     ///
     /// # Returns
     ///
     /// A `Generator` instance initialized with the provided `StructInfo`.
-    pub fn new(info: &'info Container<'info>) -> Self {
-        info.group_collection()
-            .values()
+    pub fn new(infos: &'info [Container<'info>]) -> Self {
+        infos
+            .iter()
+            .flat_map(|info| info.group_collection().values())
             .for_each(|group| group.check());
+        infos
+            .iter()
+            .flat_map(|info| info.constraint_collection())
+            .for_each(|constraint| constraint.check());
 
         Generator {
-            info,
-            data_gen: DataGenerator::new(info),
-            target_gen: TargetGenerator::new(info),
-            builder_gen: BuilderGenerator::new(info),
+            infos,
+            data_gens: infos.iter().map(DataGenerator::new).collect(),
+            target_gen: TargetGenerator::new(infos),
+            builder_gens: infos.iter().map(BuilderGenerator::new).collect(),
         }
     }
 
@@ -47,56 +53,134 @@ impl<'info> Generator<'info> {
     /// A `TokenStream` representing the generated token stream.
     pub fn generate(&self) -> TokenStream {
         let target = self.target_gen.generate();
-        let data = self.data_gen.generate();
-        let builder = self.builder_gen.generate();
+        let containers = self
+            .infos
+            .iter()
+            .zip(self.data_gens.iter().zip(self.builder_gens.iter()))
+            .map(|(info, (data_gen, builder_gen))| {
+                let data = data_gen.generate();
+                let builder = builder_gen.generate();
 
-        if self.info.generate_module() {
-            let mod_ident = self.info.mod_ident();
-            let target_ident = self.info.ident();
-            quote!(
-                #target
-                mod #mod_ident {
-                    use super::#target_ident;
-                    #builder
-                    #data
+                if info.generate_module() {
+                    let mod_ident = info.mod_ident();
+                    let builder_ident = info.builder_ident();
+                    let vis = info.builder_vis();
+                    quote!(
+                        #[doc(hidden)]
+                        mod #mod_ident {
+                            use super::*;
+                            #builder
+                            #data
+                        }
+                        #vis use #mod_ident::#builder_ident;
+                    )
+                } else {
+                    quote!(
+                        #builder
+                        #data
+                    )
                 }
-            )
-        } else {
-            quote!(
-                #target
-                #builder
-                #data
-            )
-        }
+            });
+
+        quote!(
+            #target
+            #(#containers)*
+        )
     }
 }
 
 mod util {
-    use crate::info::{FieldCollection, TrackedField};
+    use crate::info::{Field, FieldCollection, FieldKind, TrackedField};
     use proc_macro2::TokenStream;
     use quote::quote;
+    use syn::parse_quote;
 
-    /// Generates const generics with boolean values and returns a token stream.
+    /// Converts a visibility, so that an item inside of a generated module is visible to the same scope as it would be outside of it.
     ///
     /// # Arguments
     ///
-    /// - `value`: A boolean value to set for the const generics.
+    /// - `vis`: The visibility the item would have outside of the module.
+    ///
+    /// # Returns
+    ///
+    /// A `syn::Visibility` that can be used inside of the module.
+    pub fn vis_in_module(vis: &syn::Visibility) -> syn::Visibility {
+        match vis {
+            syn::Visibility::Public(_) => vis.clone(),
+            syn::Visibility::Inherited => parse_quote!(pub(super)),
+            syn::Visibility::Restricted(restricted) => {
+                let mut path = restricted.path.as_ref().clone();
+                match path.segments.first().map(|segment| segment.ident.clone()) {
+                    Some(ident) if ident == "self" => {
+                        path.segments[0].ident = syn::Ident::new("super", ident.span());
+                    }
+                    Some(ident) if ident == "super" => path.segments.insert(0, ident.into()),
+                    _ => return vis.clone(),
+                }
+                parse_quote!(pub(in #path))
+            }
+        }
+    }
+
+    /// Checks if an item with visibility `vis` is visible in fewer places than an item with visibility `other`.
+    /// Restrictions to a path are assumed to be less visible than `pub(crate)`, and more visible than `pub(self)`.
+    pub fn is_less_visible(vis: &syn::Visibility, other: &syn::Visibility) -> bool {
+        fn rank(vis: &syn::Visibility) -> u8 {
+            match vis {
+                syn::Visibility::Public(_) => 3,
+                syn::Visibility::Restricted(restricted) if restricted.path.is_ident("crate") => 2,
+                syn::Visibility::Restricted(restricted) if restricted.path.is_ident("self") => 0,
+                syn::Visibility::Restricted(_) => 1,
+                syn::Visibility::Inherited => 0,
+            }
+        }
+        rank(vis) < rank(other)
+    }
+
+    /// Generates the const generics of the initial state of the builder, where no fields are set, and returns a token stream.
     ///
     /// # Returns
     ///
     /// A `TokenStream` representing the generated const generics.
-    pub fn const_generics_all_valued(
-        value: bool,
+    pub fn const_generics_initial(
         fields: &FieldCollection,
         generics: &syn::Generics,
     ) -> TokenStream {
         let mut all = fields
             .iter()
             .filter_map(TrackedField::new)
-            .map(|_| quote!(#value));
+            .map(|field| field.const_initial());
         add_const_valued_generics_for_type(&mut all, generics)
     }
 
+    /// Generates the value of a field in the data struct when it isn't set yet.
+    /// Collections start out with their default, while all other fields are `None`.
+    pub fn data_field_initial_value(field: &Field) -> TokenStream {
+        if field.each().is_some() {
+            match field.default() {
+                Some(default) => quote!(#default),
+                None => quote!(::core::default::Default::default()),
+            }
+        } else {
+            quote!(None)
+        }
+    }
+
+    /// Wraps the value of a field of the target in the way it's stored in the data struct.
+    /// Mandatory fields and fields with a default that aren't of type `Option` are stored in an `Option`.
+    pub fn data_field_from_value(field: &Field, value: TokenStream) -> TokenStream {
+        match field.kind() {
+            FieldKind::Mandatory if !field.is_option_type() => quote!(Some(#value)),
+            FieldKind::Optional if field.each().is_none() && field.default().is_some() => {
+                quote!(Some(#value))
+            }
+            FieldKind::Mandatory
+            | FieldKind::Optional
+            | FieldKind::Grouped
+            | FieldKind::Skipped => value,
+        }
+    }
+
     /// Adds valued const generics to the target structs `syn::Generics` and returns a `Tokenstream` instance.
     ///
     /// # Returns
@@ -6,7 +6,7 @@ use quote::{quote, ToTokens};
 /// The `TargetGenerator` struct is responsible for generating code for the target struct implementation
 /// of the builder pattern based on the provided `Container`.
 pub struct TargetGenerator<'info> {
-    info: &'info Container<'info>,
+    infos: &'info [Container<'info>],
 }
 
 impl<'info> TargetGenerator<'info> {
@@ -14,13 +14,13 @@ impl<'info> TargetGenerator<'info> {
     ///
     /// # Arguments
     ///
-    /// - `info`: The `Container` containing all the information of the data container.
+    /// - `infos`: The `Container`s containing all the information of the data container.
     ///
     /// # Returns
     ///
     /// A `TargetGenerator` instance initialized with the provided information.
-    pub fn new(info: &'info Container<'info>) -> Self {
-        Self { info }
+    pub fn new(infos: &'info [Container<'info>]) -> Self {
+        Self { infos }
     }
 
     /// Generates the target struct's builder implementation code and returns a token stream.
@@ -29,32 +29,36 @@ impl<'info> TargetGenerator<'info> {
     ///
     /// A `TokenStream` representing the generated code for the builder implementation.
     pub fn generate(&self) -> TokenStream {
-        self.generate_impl()
+        match self.infos {
+            [info] if info.variant().is_none() => self.generate_impl(info),
+            infos => self.generate_enum_impl(infos),
+        }
     }
 
     /// Generates the actual implementation code for the target struct.
-    fn generate_impl(&self) -> TokenStream {
-        let target_ident = self.info.ident();
-        let builder_ident = self.info.builder_ident();
-
-        let builder_impl = if self.info.generate_module() {
-            let mod_ident = self.info.mod_ident();
-            quote!(#mod_ident::#builder_ident)
-        } else {
-            builder_ident.to_token_stream()
-        };
-
-        let const_generics = util::const_generics_all_valued(
-            false,
-            self.info.field_collection(),
-            self.info.generics(),
-        );
-        let (impl_generics, type_generics, where_clause) = self.info.generics().split_for_impl();
+    ///
+    /// If the builder is less visible than the struct, it can't be exposed through the `Builder` trait.
+    /// An inherent `builder()` method with the visibility of the builder is generated instead.
+    fn generate_impl(&self, info: &Container) -> TokenStream {
+        let target_ident = info.ident();
+        let builder_impl = self.builder_impl(info);
+        let (impl_generics, type_generics, where_clause) = info.generics().split_for_impl();
 
-        let documentation = format!("Creates an instance of [`{}`]", self.info.builder_ident());
+        let documentation = format!("Creates an instance of [`{}`]", info.builder_ident());
+        let builder_vis = info.builder_vis();
+        if util::is_less_visible(builder_vis, info.vis()) {
+            return quote! {
+                impl #impl_generics #target_ident #type_generics #where_clause {
+                    #[doc = #documentation]
+                    #builder_vis fn builder() -> #builder_impl {
+                        <#builder_impl>::new()
+                    }
+                }
+            };
+        }
         quote! {
             impl #impl_generics Builder for #target_ident #type_generics #where_clause {
-                type BuilderImpl = #builder_impl #const_generics;
+                type BuilderImpl = #builder_impl;
 
                 #[doc = #documentation]
                 fn builder() -> Self::BuilderImpl  {
@@ -63,4 +67,119 @@ impl<'info> TargetGenerator<'info> {
             }
         }
     }
+
+    /// Generates the implementation code for the target enum.
+    ///
+    /// The `Builder` implementation of an enum returns a variant selector,
+    /// which has a method for every variant that returns the builder of that variant.
+    /// Every variant builder can also be created directly using `{variant}_builder()`.
+    fn generate_enum_impl(&self, infos: &[Container]) -> TokenStream {
+        let Some(first) = infos.first() else {
+            return TokenStream::new();
+        };
+        let target_ident = first.ident();
+        let selector_ident = first.selector_ident();
+        let vis = first.selector_vis();
+        let generics = first.generics();
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+        let selector_methods = infos.iter().map(|info| {
+            let method_ident = info.selector_method_ident();
+            let builder_vis = info.builder_vis();
+            let builder_impl = self.builder_impl(info);
+            let documentation = format!("Creates an instance of [`{}`]", info.builder_ident());
+            quote!(
+                #[doc = #documentation]
+                #builder_vis fn #method_ident(self) -> #builder_impl {
+                    <#builder_impl>::new()
+                }
+            )
+        });
+
+        let entry_points = infos.iter().map(|info| {
+            let variant = info
+                .variant()
+                .expect("Enum containers are always associated with a variant");
+            let method_ident = quote::format_ident!(
+                "{}_builder",
+                info.selector_method_ident()
+                    .expect("Enum containers are always associated with a variant")
+            );
+            let builder_vis = info.builder_vis();
+            let builder_impl = self.builder_impl(info);
+            let documentation = format!(
+                "Creates an instance of [`{}`], which builds [`{target_ident}::{variant}`]",
+                info.builder_ident()
+            );
+            quote!(
+                #[doc = #documentation]
+                #builder_vis fn #method_ident() -> #builder_impl {
+                    <#builder_impl>::new()
+                }
+            )
+        });
+
+        let selector_documentation = format!(
+            "Selects the variant of [`{target_ident}`] to build, derived using the `const_typed_builder` crate"
+        );
+        let builder_documentation = format!("Creates an instance of [`{selector_ident}`]");
+        // A selector that is less visible than the enum can't be exposed through the `Builder` trait
+        let builder_impl = if util::is_less_visible(vis, first.vis()) {
+            quote! {
+                impl #impl_generics #target_ident #type_generics #where_clause {
+                    #[doc = #builder_documentation]
+                    #vis fn builder() -> #selector_ident #type_generics {
+                        #selector_ident {
+                            __phantom: ::core::marker::PhantomData,
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics Builder for #target_ident #type_generics #where_clause {
+                    type BuilderImpl = #selector_ident #type_generics;
+
+                    #[doc = #builder_documentation]
+                    fn builder() -> Self::BuilderImpl  {
+                        #selector_ident {
+                            __phantom: ::core::marker::PhantomData,
+                        }
+                    }
+                }
+            }
+        };
+
+        quote! {
+            #[doc = #selector_documentation]
+            #vis struct #selector_ident #impl_generics #where_clause {
+                __phantom: ::core::marker::PhantomData<fn() -> #target_ident #type_generics>,
+            }
+
+            impl #impl_generics #selector_ident #type_generics #where_clause {
+                #(#selector_methods)*
+            }
+
+            impl #impl_generics #target_ident #type_generics #where_clause {
+                #(#entry_points)*
+            }
+
+            #builder_impl
+        }
+    }
+
+    /// Generates the type of the builder in its initial state.
+    fn builder_impl(&self, info: &Container) -> TokenStream {
+        let builder_ident = info.builder_ident();
+
+        let builder_path = if info.generate_module() {
+            let mod_ident = info.mod_ident();
+            quote!(#mod_ident::#builder_ident)
+        } else {
+            builder_ident.to_token_stream()
+        };
+
+        let const_generics = util::const_generics_initial(info.field_collection(), info.generics());
+        quote!(#builder_path #const_generics)
+    }
 }
@@ -0,0 +1,217 @@
+use crate::symbol::Symbol;
+use itertools::Itertools;
+use proc_macro_error::{emit_error, emit_warning};
+use std::{collections::BTreeSet, fmt::Display};
+
+/// A type alias for a collection of `Constraint` instances.
+pub type ConstraintCollection = Vec<Constraint>;
+
+/// Represents a constraint on the fields that are set, defined using `#[builder(requires = other)]`, `#[builder(conflicts_with = other)]`
+/// or `#[constraint(...)]`.
+#[derive(Debug, Clone)]
+pub struct Constraint {
+    kind: ConstraintKind,
+    expression: Expression,
+    description: String,
+    span: proc_macro2::Span,
+}
+
+impl Constraint {
+    /// Creates a constraint in which the field `other` has to be set if `field` is set.
+    ///
+    /// # Arguments
+    ///
+    /// - `field`: The identifier and index of the field the constraint is defined on.
+    /// - `other`: The identifier and index of the field the constraint refers to.
+    pub fn requires(
+        (field, index): (syn::Ident, usize),
+        (other, other_index): (syn::Ident, usize),
+    ) -> Self {
+        let span = other.span();
+        let description = format!("`{field}` {} `{other}`", Symbol::Requires);
+        Constraint {
+            kind: ConstraintKind::Requires,
+            expression: Expression::Any(vec![
+                Expression::Not(Box::new(Expression::Field(field, index))),
+                Expression::Field(other, other_index),
+            ]),
+            description,
+            span,
+        }
+    }
+
+    /// Creates a constraint in which the field `other` can't be set if `field` is set, and vice versa.
+    ///
+    /// # Arguments
+    ///
+    /// - `field`: The identifier and index of the field the constraint is defined on.
+    /// - `other`: The identifier and index of the field the constraint refers to.
+    pub fn conflicts_with(
+        (field, index): (syn::Ident, usize),
+        (other, other_index): (syn::Ident, usize),
+    ) -> Self {
+        let span = other.span();
+        let description = format!("`{field}` {} `{other}`", Symbol::ConflictsWith);
+        Constraint {
+            kind: ConstraintKind::ConflictsWith,
+            expression: Expression::Not(Box::new(Expression::All(vec![
+                Expression::Field(field, index),
+                Expression::Field(other, other_index),
+            ]))),
+            description,
+            span,
+        }
+    }
+
+    /// Creates a constraint from an expression defined using `#[constraint(...)]`.
+    ///
+    /// # Arguments
+    ///
+    /// - `expression`: The expression that has to be true for the fields that are set.
+    /// - `span`: The span of the expression, used for errors and warnings.
+    pub fn expression(expression: Expression, span: proc_macro2::Span) -> Self {
+        Constraint {
+            kind: ConstraintKind::Expression,
+            description: format!("`{expression}`"),
+            expression,
+            span,
+        }
+    }
+
+    /// Retrieves the kind of the constraint.
+    pub fn kind(&self) -> ConstraintKind {
+        self.kind
+    }
+
+    /// Retrieves the expression of the constraint.
+    pub fn expr(&self) -> &Expression {
+        &self.expression
+    }
+
+    /// Retrieves the indices of all fields the constraint depends on.
+    pub fn indices(&self) -> BTreeSet<usize> {
+        let mut indices = BTreeSet::new();
+        self.expression.collect_indices(&mut indices);
+        indices
+    }
+
+    /// Retrieves the index of the field that conflicts with the field at `index`, if the constraint is a conflict that involves it.
+    /// This is the case for `conflicts_with` and for expressions like `not(all(a, b))`.
+    pub fn conflicting_index(&self, index: usize) -> Option<usize> {
+        let operands = match &self.expression {
+            Expression::Not(inner) => match inner.as_ref() {
+                Expression::All(operands) => operands,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        match operands.as_slice() {
+            [Expression::Field(_, first), Expression::Field(_, second)] if *first == index => {
+                Some(*second)
+            }
+            [Expression::Field(_, first), Expression::Field(_, second)] if *second == index => {
+                Some(*first)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks if the constraint is satisfied if only the fields at `indices` are set.
+    pub fn is_valid_with(&self, indices: &[usize]) -> bool {
+        self.expression.evaluate(indices)
+    }
+
+    /// Retrieves every combination of the fields the constraint depends on that satisfies it, as the indices of the fields that are set.
+    pub fn valid_combinations(&self) -> impl Iterator<Item = Vec<usize>> + '_ {
+        self.indices()
+            .into_iter()
+            .powerset()
+            .filter(|set| self.is_valid_with(set))
+    }
+
+    /// Check if the constraint is formed correctly. Will emit errors or warnings if it can never be satisfied, or is always satisfied.
+    pub fn check(&self) {
+        let combinations = 1 << self.indices().len();
+        match self.valid_combinations().count() {
+            0 => emit_error!(
+                self.span, format!("Constraint {self} can never be satisfied");
+                hint = "Remove the constraint or change the expression"
+            ),
+            count if count == combinations => emit_warning!(
+                self.span, format!("Constraint {self} has no effect");
+                hint = "Consider removing the constraint"
+            ),
+            _ => {}
+        }
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+/// Represents the kind of a constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// The other field has to be set if the field is set.
+    Requires,
+    /// The other field can't be set if the field is set, and vice versa.
+    ConflictsWith,
+    /// A boolean expression over fields.
+    Expression,
+}
+
+/// Represents a boolean expression over the fields that are set.
+#[derive(Debug, Clone)]
+pub enum Expression {
+    /// True if the field with this identifier and index is set.
+    Field(syn::Ident, usize),
+    /// True if the inner expression is false.
+    Not(Box<Expression>),
+    /// True if all of the inner expressions are true.
+    All(Vec<Expression>),
+    /// True if any of the inner expressions is true.
+    Any(Vec<Expression>),
+}
+
+impl Expression {
+    /// Evaluates the expression if only the fields at `indices` are set.
+    pub fn evaluate(&self, indices: &[usize]) -> bool {
+        match self {
+            Expression::Field(_, index) => indices.contains(index),
+            Expression::Not(inner) => !inner.evaluate(indices),
+            Expression::All(operands) => operands.iter().all(|operand| operand.evaluate(indices)),
+            Expression::Any(operands) => operands.iter().any(|operand| operand.evaluate(indices)),
+        }
+    }
+
+    fn collect_indices(&self, indices: &mut BTreeSet<usize>) {
+        match self {
+            Expression::Field(_, index) => {
+                indices.insert(*index);
+            }
+            Expression::Not(inner) => inner.collect_indices(indices),
+            Expression::All(operands) | Expression::Any(operands) => operands
+                .iter()
+                .for_each(|operand| operand.collect_indices(indices)),
+        }
+    }
+
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expression::Field(ident, _) => write!(f, "{ident}"),
+            Expression::Not(inner) => write!(f, "{}({inner})", Symbol::Not),
+            Expression::All(operands) => {
+                write!(f, "{}({})", Symbol::All, operands.iter().join(", "))
+            }
+            Expression::Any(operands) => {
+                write!(f, "{}({})", Symbol::Any, operands.iter().join(", "))
+            }
+        }
+    }
+}
@@ -1,12 +1,77 @@
-use super::field::FieldCollection;
+use super::constraint::ConstraintCollection;
+use super::field::{Field, FieldCollection, FieldKind};
 use super::group::GroupCollection;
 use convert_case::{Case, Casing};
 use quote::format_ident;
+use syn::parse_quote;
 
 #[derive(Debug, Clone, Copy)]
 pub enum SolverKind {
     BruteForce,
     Compiler,
+    Counting,
+}
+
+/// The maximum number of grouped fields for which `#[builder(solver = auto)]` uses `BruteForce`.
+const AUTO_BRUTE_FORCE_MAX_FIELDS: usize = 8;
+
+/// Represents the settings of the build method, which can be set using `#[builder(build_fn(...))]`.
+#[derive(Debug, Clone, Default)]
+pub struct BuildFnSettings {
+    /// The name of the build method.
+    pub name: Option<syn::Ident>,
+    /// The visibility of the build method.
+    pub vis: Option<syn::Visibility>,
+    /// Whether a build method that returns a `Box` is generated.
+    pub boxed: bool,
+    /// Whether a build method that returns an `Arc` is generated.
+    pub arc: bool,
+    /// Whether a build method that converts into any type that implements `From` is generated.
+    pub into: bool,
+    /// The function that validates the target before it's returned by the build method.
+    pub validate: Option<syn::Path>,
+    /// The error type returned by the build method if the target is validated.
+    pub error: Option<syn::Type>,
+}
+
+/// Represents the settings of a container, which can be set using `#[builder(...)]` attributes.
+#[derive(Debug, Clone)]
+pub struct ContainerSettings {
+    /// The solver used to find all possible valid combinations for the groups.
+    /// `None` if it's chosen based on the number of grouped fields, using `#[builder(solver = auto)]`.
+    pub solver_kind: Option<SolverKind>,
+    /// The name of the generated builder struct.
+    pub builder_name: Option<syn::Ident>,
+    /// The visibility of the generated builder struct.
+    pub builder_vis: Option<syn::Visibility>,
+    /// The name of the generated data struct.
+    pub data_name: Option<syn::Ident>,
+    /// The name of the generated variant selector, which is only used for enums.
+    pub selector_name: Option<syn::Ident>,
+    /// The visibility of the generated variant selector, which is only used for enums.
+    pub selector_vis: Option<syn::Visibility>,
+    /// Whether the builder and data struct are generated inside of a private module.
+    pub module: bool,
+    /// The settings of the build method.
+    pub build_fn: BuildFnSettings,
+    /// The prefix of the names of the setters.
+    pub setter_prefix: Option<String>,
+}
+
+impl Default for ContainerSettings {
+    fn default() -> Self {
+        ContainerSettings {
+            solver_kind: Some(SolverKind::BruteForce),
+            builder_name: None,
+            builder_vis: None,
+            data_name: None,
+            selector_name: None,
+            selector_vis: None,
+            module: false,
+            build_fn: BuildFnSettings::default(),
+            setter_prefix: None,
+        }
+    }
 }
 
 /// Represents the information about a struct used for code generation.
@@ -14,16 +79,20 @@ pub enum SolverKind {
 pub struct Container<'a> {
     /// The identifier of the struct.
     ident: &'a syn::Ident,
+    /// The identifier of the variant, if the container is an enum variant.
+    variant: Option<&'a syn::Ident>,
     /// The visibility of the struct.
     vis: &'a syn::Visibility,
     /// The generics of the struct.
     generics: &'a syn::Generics,
     /// A map of group names to their respective `GroupInfo`.
     groups: GroupCollection,
+    /// The constraints between fields, defined using `requires` and `conflicts_with`.
+    constraints: ConstraintCollection,
     /// A collection of `FieldInfo` instances representing struct fields.
     field_collection: FieldCollection<'a>,
-    /// The solver used to find all possible valid combinations for the groups
-    solver_kind: SolverKind,
+    /// The settings of the container.
+    settings: ContainerSettings,
 }
 
 impl<'a> Container<'a> {
@@ -40,30 +109,156 @@ impl<'a> Container<'a> {
         vis: &'a syn::Visibility,
         generics: &'a syn::Generics,
         ident: &'a syn::Ident,
+        variant: Option<&'a syn::Ident>,
         group_collection: GroupCollection,
         field_collection: FieldCollection<'a>,
-        solver_kind: SolverKind,
+        settings: ContainerSettings,
     ) -> Self {
         Container {
             ident,
+            variant,
             vis,
             generics,
             groups: group_collection,
+            constraints: ConstraintCollection::new(),
             field_collection,
-            solver_kind,
+            settings,
         }
     }
 
+    /// Sets the constraints between the fields of the container, which are resolved after the fields are parsed.
+    pub fn with_constraint_collection(
+        mut self,
+        constraint_collection: ConstraintCollection,
+    ) -> Self {
+        self.constraints = constraint_collection;
+        self
+    }
+
     /// Retrieves the identifier of the struct.
     pub fn ident(&self) -> &syn::Ident {
         self.ident
     }
 
+    /// Retrieves the identifier of the variant, if the container is an enum variant.
+    pub fn variant(&self) -> Option<&syn::Ident> {
+        self.variant
+    }
+
+    /// Retrieves the path used to construct the target, e.g. `Foo` or `Foo::Bar` for enum variants.
+    pub fn target_path(&self) -> syn::Path {
+        let mut path = syn::Path::from(self.ident.clone());
+        if let Some(variant) = self.variant {
+            path.segments.push(variant.clone().into());
+        }
+        path
+    }
+
+    /// Retrieves the name of the target as it should appear in the documentation.
+    pub fn target_name(&self) -> String {
+        match self.variant {
+            Some(variant) => format!("{}::{}", self.ident, variant),
+            None => self.ident.to_string(),
+        }
+    }
+
     /// Retrieves the visibility of the struct.
     pub fn vis(&self) -> &syn::Visibility {
         self.vis
     }
 
+    /// Retrieves the visibility of the generated builder struct, which defaults to the visibility of the struct.
+    pub fn builder_vis(&self) -> &syn::Visibility {
+        self.settings.builder_vis.as_ref().unwrap_or(self.vis)
+    }
+
+    /// Retrieves the visibility of the generated variant selector, which defaults to the visibility of the enum.
+    pub fn selector_vis(&self) -> &syn::Visibility {
+        self.settings.selector_vis.as_ref().unwrap_or(self.vis)
+    }
+
+    /// Retrieves the identifier of the build method, which defaults to `build`.
+    /// The identifier is prefixed with `try_` if the target is validated.
+    pub fn build_fn_ident(&self) -> syn::Ident {
+        let ident = match &self.settings.build_fn.name {
+            Some(name) => name.clone(),
+            None => format_ident!("build"),
+        };
+        if self.settings.build_fn.validate.is_some() {
+            format_ident!("try_{}", ident)
+        } else {
+            ident
+        }
+    }
+
+    /// Retrieves the visibility of the build method, which defaults to `pub`.
+    pub fn build_fn_vis(&self) -> syn::Visibility {
+        match &self.settings.build_fn.vis {
+            Some(vis) => vis.clone(),
+            None => parse_quote!(pub),
+        }
+    }
+
+    /// Retrieves the error type returned by the fallible methods of the builder, like `try_build`,
+    /// which defaults to `Box<dyn std::error::Error + Send + Sync>`.
+    pub fn error_ty(&self) -> syn::Type {
+        match &self.settings.build_fn.error {
+            Some(error) => error.clone(),
+            None => parse_quote!(
+                ::std::boxed::Box<
+                    dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync,
+                >
+            ),
+        }
+    }
+
+    /// Retrieves the settings of the build method.
+    pub fn build_fn_settings(&self) -> &BuildFnSettings {
+        &self.settings.build_fn
+    }
+
+    /// Retrieves the identifier of the setter of a field, which is the name of the field with the setter prefix
+    /// unless the setter is renamed.
+    pub fn setter_ident(&self, field: &Field) -> syn::Ident {
+        match (field.rename(), &self.settings.setter_prefix) {
+            (Some(rename), _) => rename.clone(),
+            (None, Some(prefix)) => format_ident!("{}{}", prefix, field.ident()),
+            (None, None) => field.ident().clone(),
+        }
+    }
+
+    /// Retrieves the identifier of the method that clears a field, which is `clear_` followed by the name of the field.
+    /// Returns an error if another method of the builder already has this identifier, like the setter of a field named `clear_foo`.
+    pub fn clear_ident(&self, field: &Field) -> syn::Result<syn::Ident> {
+        let clear_ident = format_ident!("clear_{}", field.ident());
+        let collides = self
+            .field_collection
+            .iter()
+            .filter(|other| other.kind() != FieldKind::Skipped)
+            .flat_map(|other| [Some(self.setter_ident(other)), other.each().cloned()])
+            .flatten()
+            .chain(std::iter::once(self.build_fn_ident()))
+            .any(|ident| ident == clear_ident);
+        if collides {
+            Err(syn::Error::new_spanned(
+                field.ident(),
+                format!("The method `{clear_ident}` that clears this field collides with another method of the builder"),
+            ))
+        } else {
+            Ok(clear_ident)
+        }
+    }
+
+    /// Retrieves the visibility of the setter of a field, which defaults to the visibility of the field.
+    /// The fields of an enum variant can't have a visibility, so their setters default to the visibility of the builder.
+    pub fn setter_vis(&self, field: &Field) -> syn::Visibility {
+        match field.setter_vis() {
+            Some(vis) => vis.clone(),
+            None if self.variant.is_some() => self.builder_vis().clone(),
+            None => field.vis().clone(),
+        }
+    }
+
     /// Retrieves the generics of the struct.
     pub fn generics(&self) -> &syn::Generics {
         self.generics
@@ -71,16 +266,42 @@ impl<'a> Container<'a> {
 
     /// Retrieves the identifier of the generated builder struct.
     pub fn builder_ident(&self) -> syn::Ident {
-        format_ident!("{}{}", self.ident, "Builder")
+        if let Some(name) = &self.settings.builder_name {
+            return name.clone();
+        }
+        match self.variant {
+            Some(variant) => format_ident!("{}{}{}", self.ident, variant, "Builder"),
+            None => format_ident!("{}{}", self.ident, "Builder"),
+        }
     }
 
     /// Retrieves the identifier of the generated data struct.
     pub fn data_ident(&self) -> syn::Ident {
-        format_ident!("{}{}", self.ident, "Data")
+        if let Some(name) = &self.settings.data_name {
+            return name.clone();
+        }
+        match self.variant {
+            Some(variant) => format_ident!("{}{}{}", self.ident, variant, "Data"),
+            None => format_ident!("{}{}", self.ident, "Data"),
+        }
+    }
+
+    /// Retrieves the identifier of the generated variant selector, which is only used for enums.
+    pub fn selector_ident(&self) -> syn::Ident {
+        match &self.settings.selector_name {
+            Some(name) => name.clone(),
+            None => format_ident!("{}{}", self.ident, "Builder"),
+        }
+    }
+
+    /// Retrieves the identifier of the method that selects this variant, e.g. `connect` for `Msg::Connect`.
+    pub fn selector_method_ident(&self) -> Option<syn::Ident> {
+        self.variant
+            .map(|variant| format_ident!("{}", variant.to_string().to_case(Case::Snake)))
     }
 
     /// Retrieves a reference to the collection of `FieldInfo` instances representing struct fields.
-    pub fn field_collection(&self) -> &FieldCollection {
+    pub fn field_collection(&self) -> &FieldCollection<'a> {
         &self.field_collection
     }
 
@@ -89,20 +310,46 @@ impl<'a> Container<'a> {
         &self.groups
     }
 
-    /// Retrieves the solver type used to find all possible valid combinations for the groups
+    /// Retrieves a reference to the constraints between fields.
+    pub fn constraint_collection(&self) -> &ConstraintCollection {
+        &self.constraints
+    }
+
+    /// Retrieves the indices of the fields that conflict with the field at `index`.
+    pub fn conflicting_indices(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.constraints
+            .iter()
+            .filter_map(move |constraint| constraint.conflicting_index(index))
+    }
+
+    /// Retrieves the solver type used to find all possible valid combinations for the groups.
+    /// If the solver is chosen automatically, it's resolved based on the number of grouped fields.
     pub fn solver_kind(&self) -> SolverKind {
-        self.solver_kind
+        self.settings.solver_kind.unwrap_or_else(|| {
+            let grouped_fields = self
+                .field_collection
+                .iter()
+                .filter(|field| field.kind() == FieldKind::Grouped)
+                .count();
+            if grouped_fields > AUTO_BRUTE_FORCE_MAX_FIELDS {
+                SolverKind::Counting
+            } else {
+                SolverKind::BruteForce
+            }
+        })
     }
 
     pub fn data_field_ident(&self) -> syn::Ident {
         format_ident!("__{}", self.data_ident().to_string().to_case(Case::Snake))
     }
 
+    /// Retrieves the identifier of the module the builder and data struct are generated in.
     pub fn mod_ident(&self) -> syn::Ident {
         format_ident!("{}", self.builder_ident().to_string().to_case(Case::Snake))
     }
 
+    /// Checks if the builder and data struct are generated inside of a private module.
     pub fn generate_module(&self) -> bool {
-        false
+        self.settings.module
     }
 }
@@ -1,6 +1,9 @@
+use super::group::GroupType;
 use crate::util::{inner_type, is_option};
-use quote::format_ident;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
 use std::ops::Deref;
+use syn::parse_quote;
 
 /// A type alias for a collection of `FieldInfo` instances.
 pub type FieldCollection<'a> = Vec<Field<'a>>;
@@ -18,20 +21,55 @@ pub enum FieldKind {
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SetterKind {
     Standard,
-    Propagate,
     Into,
     AsMut,
     AsRef,
 }
 
+/// Represents the settings of a field, which can be set using `#[builder(...)]` attributes.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FieldSettings {
+    /// The value used when the field is not set.
+    pub default: Option<syn::Expr>,
+    /// The function that validates the value of the field when it's set.
+    pub validate: Option<syn::Path>,
+    /// The error type returned by the setter if the field is validated.
+    pub error: Option<syn::Type>,
+    /// The name of the setter, which overrides the name derived from the field.
+    pub rename: Option<syn::Ident>,
+    /// The visibility of the setter.
+    pub vis: Option<syn::Visibility>,
+    /// The name of the setter that adds a single item to a collection.
+    pub each: Option<syn::Ident>,
+    /// The minimum number of items that have to be added to the collection.
+    pub min: Option<usize>,
+    /// The maximum number of items that can be added to the collection.
+    pub max: Option<usize>,
+    /// Whether a separate setter that takes an `Option` is generated for a field of type `Option`.
+    pub maybe: bool,
+    /// Whether an optional field can only be set once, which is enforced by tracking it.
+    pub once: bool,
+    /// The `maybe` and `once` specifiers that are set on the field itself, instead of being assumed from the container.
+    pub explicit: Vec<syn::Path>,
+    /// The suffix of the setter that propagates the construction of the field to its builder.
+    pub propagate: Option<syn::Ident>,
+    /// The names of the fields that have to be set if this field is set.
+    pub requires: Vec<syn::Ident>,
+    /// The names of the fields that can't be set if this field is set.
+    pub conflicts_with: Vec<syn::Ident>,
+}
+
 /// Represents the information about a struct field used for code generation.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Field<'a> {
     ty: &'a syn::Type,
-    ident: &'a syn::Ident,
+    vis: &'a syn::Visibility,
+    ident: syn::Ident,
+    member: syn::Member,
     index: usize,
     kind: FieldKind,
     setter_kind: SetterKind,
+    settings: FieldSettings,
 }
 
 impl<'a> Field<'a> {
@@ -39,32 +77,46 @@ impl<'a> Field<'a> {
     ///
     /// # Arguments
     ///
-    /// - `field`: A reference to the `syn::Field` representing the field.
-    /// - `struct_settings`: A mutable reference to `StructSettings` for the struct containing this field.
+    /// - `field`: The field as it's defined in the struct.
+    /// - `ident`: The identifier used for the setter and the data field, `_0`, `_1`, etc. for unnamed fields by default.
+    /// - `member`: The member used to access the field on the target, which is an index for unnamed fields.
     /// - `index`: The index of the field within the struct.
+    /// - `kind`: The kind of the field.
+    /// - `setter_kind`: The kind of setter that is generated for the field.
+    /// - `settings`: The settings of the field, like the value used when the field is not set.
     ///
     /// # Returns
     ///
     /// An otpional `FieldInfo` instance if successful.
     pub fn new(
-        ident: &'a syn::Ident,
-        ty: &'a syn::Type,
+        field: &'a syn::Field,
+        ident: syn::Ident,
+        member: syn::Member,
         index: usize,
         kind: FieldKind,
         setter_kind: SetterKind,
+        settings: FieldSettings,
     ) -> Self {
         Self {
             ident,
+            member,
             index,
-            ty,
+            ty: &field.ty,
+            vis: &field.vis,
             kind,
             setter_kind,
+            settings,
         }
     }
 
     /// Retrieves the identifier of the field.
     pub fn ident(&self) -> &syn::Ident {
-        self.ident
+        &self.ident
+    }
+
+    /// Retrieves the member of the field, which is either its name or its index.
+    pub fn member(&self) -> &syn::Member {
+        &self.member
     }
 
     /// Checks if the field's type is an Option.
@@ -77,9 +129,127 @@ impl<'a> Field<'a> {
         self.ty
     }
 
+    /// Retrieves the visibility of the field.
+    pub fn vis(&self) -> &syn::Visibility {
+        self.vis
+    }
+
+    /// Retrieves the visibility of the setter, if it's defined on the field.
+    pub fn setter_vis(&self) -> Option<&syn::Visibility> {
+        self.settings.vis.as_ref()
+    }
+
+    /// Retrieves the name of the setter, if it's defined on the field.
+    pub fn rename(&self) -> Option<&syn::Ident> {
+        self.settings.rename.as_ref()
+    }
+
     /// Retrieves the inner type of the field if it is wrapped in an Option
     pub fn inner_type(&self) -> Option<&syn::Type> {
-        inner_type(self.ty)
+        if self.is_option_type() {
+            inner_type(self.ty)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the field is an optional field of type `Option`. The setter of these fields takes an `Option` as well.
+    pub fn is_optional_option(&self) -> bool {
+        self.kind == FieldKind::Optional && self.is_option_type()
+    }
+
+    /// Retrieves the name of the setter that adds a single item to the collection, if the field has one.
+    pub fn each(&self) -> Option<&syn::Ident> {
+        self.settings.each.as_ref()
+    }
+
+    /// Retrieves the suffix of the setter that propagates the construction of the field to its builder, if the field has one.
+    pub fn propagate(&self) -> Option<&syn::Ident> {
+        self.settings.propagate.as_ref()
+    }
+
+    /// Retrieves the names of the fields that have to be set if this field is set.
+    pub fn requires(&self) -> &[syn::Ident] {
+        &self.settings.requires
+    }
+
+    /// Retrieves the names of the fields that can't be set if this field is set.
+    pub fn conflicts_with(&self) -> &[syn::Ident] {
+        &self.settings.conflicts_with
+    }
+
+    /// Tracks the field like a grouped field, because it's part of a constraint.
+    /// A separate setter that takes an `Option` can't be generated for these fields, and they can always only be set once,
+    /// so `maybe` and `once` that are assumed from the container are reset. The explicit specifiers are rejected by the parser.
+    pub fn constrain(&mut self) {
+        self.kind = FieldKind::Grouped;
+        self.settings.maybe = false;
+        self.settings.once = false;
+    }
+
+    /// Checks if the number of items added to the collection is tracked, which is the case if it has a minimum or a maximum.
+    pub fn is_counted(&self) -> bool {
+        self.settings.min.is_some() || self.settings.max.is_some()
+    }
+
+    /// Retrieves the bounds of the number of items added to the collection, as the group types they correspond to.
+    pub fn count_bounds(&self) -> Vec<GroupType> {
+        let min = self.settings.min.map(GroupType::AtLeast);
+        let max = self.settings.max.map(GroupType::AtMost);
+        min.into_iter().chain(max).collect()
+    }
+
+    /// Retrieves the highest count that is tracked. Without a maximum, the count saturates at the minimum.
+    pub fn count_cap(&self) -> usize {
+        self.settings.max.or(self.settings.min).unwrap_or_default()
+    }
+
+    /// Checks if items can be added to the collection when it contains `count` items.
+    pub fn can_add_item(&self, count: usize) -> bool {
+        match self.settings.max {
+            Some(max) => count < max,
+            None => true,
+        }
+    }
+
+    /// Retrieves all tracked counts that satisfy the bounds of the collection.
+    pub fn valid_counts(&self) -> impl Iterator<Item = usize> + '_ {
+        let bounds = self.count_bounds();
+        (0..=self.count_cap()).filter(move |count| {
+            bounds
+                .iter()
+                .all(|bound| bound.is_valid_count(*count, self.count_cap()))
+        })
+    }
+
+    /// Checks if a separate setter that takes an `Option` is generated for the field, called `maybe_{field}`.
+    pub fn maybe(&self) -> bool {
+        self.settings.maybe
+    }
+
+    /// Checks if the field can only be set once. Mandatory and grouped fields can always only be set once.
+    pub fn once(&self) -> bool {
+        self.settings.once
+    }
+
+    /// Retrieves the `maybe` and `once` specifiers that are set on the field itself.
+    pub fn explicit_specifiers(&self) -> &[syn::Path] {
+        &self.settings.explicit
+    }
+
+    /// Retrieves the value used when the field is not set, if it has one.
+    pub fn default(&self) -> Option<&syn::Expr> {
+        self.settings.default.as_ref()
+    }
+
+    /// Retrieves the function that validates the value of the field, if it has one.
+    pub fn validate(&self) -> Option<&syn::Path> {
+        self.settings.validate.as_ref()
+    }
+
+    /// Retrieves the error type returned by the setter if the field is validated, if it's defined on the field.
+    pub fn error(&self) -> Option<&syn::Type> {
+        self.settings.error.as_ref()
     }
 
     /// Retrieves the kind of the field, which can be Optional, Mandatory, Skipped or Grouped.
@@ -116,6 +286,8 @@ impl<'a> Ord for Field<'a> {
 pub enum TrackedFieldKind {
     Mandatory,
     Grouped,
+    Counted,
+    Optional,
 }
 pub struct TrackedField<'a> {
     field: &'a Field<'a>,
@@ -123,9 +295,19 @@ pub struct TrackedField<'a> {
 }
 
 impl<'a> TrackedField<'a> {
-    /// Creates a [`TrackedField`] if the input [`Field`] is Mandatory or Grouped.
+    /// Creates a [`TrackedField`] if the input [`Field`] is Mandatory, Grouped, a counted collection or can only be set once.
     pub fn new(field: &'a Field) -> Option<Self> {
+        if field.is_counted() {
+            return Some(Self {
+                field,
+                kind: TrackedFieldKind::Counted,
+            });
+        }
         match field.kind() {
+            FieldKind::Optional if field.once() => Some(Self {
+                field,
+                kind: TrackedFieldKind::Optional,
+            }),
             FieldKind::Optional | FieldKind::Skipped => None,
             FieldKind::Mandatory => Some(Self {
                 field,
@@ -137,10 +319,31 @@ impl<'a> TrackedField<'a> {
             }),
         }
     }
-    /// Retrieves the kind of the field, which can be Mandatory, or Grouped.
+    /// Retrieves the kind of the field, which can be Mandatory, Grouped, Counted or Optional.
     pub fn kind(&self) -> &TrackedFieldKind {
         &self.kind
     }
+
+    /// Generates the const generic parameter that tracks the field, which is a `bool` or a `usize` for counted collections.
+    pub fn const_param(&self) -> syn::GenericParam {
+        let ident = self.const_ident();
+        match self.kind {
+            TrackedFieldKind::Mandatory
+            | TrackedFieldKind::Grouped
+            | TrackedFieldKind::Optional => parse_quote!(const #ident: bool),
+            TrackedFieldKind::Counted => parse_quote!(const #ident: usize),
+        }
+    }
+
+    /// Generates the value of the const generic in the initial state of the builder, which is `false` or `0`.
+    pub fn const_initial(&self) -> TokenStream {
+        match self.kind {
+            TrackedFieldKind::Mandatory
+            | TrackedFieldKind::Grouped
+            | TrackedFieldKind::Optional => quote!(false),
+            TrackedFieldKind::Counted => quote!(0),
+        }
+    }
 }
 
 impl<'a> Deref for TrackedField<'a> {
@@ -3,6 +3,7 @@ use proc_macro_error::{emit_error, emit_warning};
 use std::{
     cmp::Ordering,
     collections::{BTreeSet, HashMap},
+    fmt::Display,
     hash::Hash,
 };
 
@@ -13,6 +14,7 @@ pub type GroupCollection = HashMap<String, Group>;
 pub struct Group {
     ident: syn::Ident,
     associated_indices: BTreeSet<usize>,
+    members: Vec<syn::Ident>,
     group_type: GroupType,
 }
 
@@ -31,6 +33,7 @@ impl Group {
         Group {
             ident: name,
             associated_indices: BTreeSet::new(),
+            members: Vec::new(),
             group_type,
         }
     }
@@ -40,12 +43,13 @@ impl Group {
         &self.ident
     }
 
-    /// Retrieves the expected member count based on the group type.
-    pub fn expected_count(&self) -> usize {
+    /// Retrieves the expected member count based on the group type, if the group type has a single expected count.
+    pub fn expected_count(&self) -> Option<usize> {
         match self.group_type {
             GroupType::Exact(expected)
             | GroupType::AtLeast(expected)
-            | GroupType::AtMost(expected) => expected,
+            | GroupType::AtMost(expected) => Some(expected),
+            GroupType::Between(_, _) | GroupType::AllOrNone => None,
         }
     }
 
@@ -59,12 +63,29 @@ impl Group {
         &self.associated_indices
     }
 
+    /// Declare a field as a member of this group by name, as done in `#[groups(foo = single(bar, baz))]`
+    pub fn declare_member(&mut self, ident: syn::Ident) -> bool {
+        if self.members.contains(&ident) {
+            false
+        } else {
+            self.members.push(ident);
+            true
+        }
+    }
+
+    /// Retrieves the names of the fields that are declared as members of this group
+    pub fn members(&self) -> &[syn::Ident] {
+        &self.members
+    }
+
     /// Retrieves the function symbol associated with the group type.
     pub fn function_symbol(&self) -> Symbol {
         match self.group_type {
             GroupType::Exact(_) => Symbol::Exact,
             GroupType::AtLeast(_) => Symbol::AtLeast,
             GroupType::AtMost(_) => Symbol::AtMost,
+            GroupType::Between(_, _) => Symbol::Between,
+            GroupType::AllOrNone => Symbol::AllOrNone,
         }
     }
 
@@ -78,11 +99,13 @@ impl Group {
             .associated_indices
             .intersection(&indices.iter().copied().collect())
             .count();
-        match self.group_type {
-            GroupType::Exact(count) => applicable_indices_count == count,
-            GroupType::AtLeast(count) => applicable_indices_count >= count,
-            GroupType::AtMost(count) => applicable_indices_count <= count,
-        }
+        self.is_valid_count(applicable_indices_count)
+    }
+
+    /// Checks if a number of set members satisfies the group.
+    pub fn is_valid_count(&self, count: usize) -> bool {
+        self.group_type
+            .is_valid_count(count, self.associated_indices.len())
     }
 
     /// Check if the group is formed correctly. Will emit errors or warnings if invalid.
@@ -90,11 +113,13 @@ impl Group {
         let valid_range = 1..self.indices().len();
         if valid_range.is_empty() {
             emit_warning!(self.ident, format!("There is not an valid expected count"))
-        } else if !valid_range.contains(&self.expected_count()) {
-            emit_warning!(
-                self.ident,
-                format!("Expected count is outside of valid range {valid_range:#?}")
-            );
+        } else if let Some(expected) = self.expected_count() {
+            if !valid_range.contains(&expected) {
+                emit_warning!(
+                    self.ident,
+                    format!("Expected count is outside of valid range {valid_range:#?}")
+                );
+            }
         }
         match self.group_type() {
             GroupType::Exact(expected) => {
@@ -161,6 +186,42 @@ impl Group {
                     ),
                 }
             }
+            GroupType::Between(min, max) => {
+                if min > max {
+                    emit_error!(
+                        self.ident,
+                        "The minimum of the group can't be larger than the maximum";
+                        note = format!("Minimum: {min}, maximum: {max}")
+                    );
+                } else if *min > valid_range.end {
+                    emit_error!(
+                        self.ident,
+                        "Group can never be satisfied";
+                        note = format!("Expected amount of fields: between {} and {}, amount of available fields: {}", min, max, valid_range.end)
+                    );
+                } else if *min == 0 && *max >= valid_range.end {
+                    emit_warning!(
+                        self.ident,
+                        "Group has no effect";
+                        hint = "Consider removing the group"
+                    );
+                } else if min == max {
+                    emit_warning!(
+                        self.ident,
+                        "Group only allows a single amount of fields";
+                        hint = "Consider using {}({}) instead", Symbol::Exact, min
+                    );
+                }
+            }
+            GroupType::AllOrNone => {
+                if valid_range.end < 2 {
+                    emit_warning!(
+                        self.ident,
+                        "Group has no effect";
+                        hint = "Consider removing the group"
+                    );
+                }
+            }
         }
     }
 }
@@ -179,7 +240,7 @@ impl Hash for Group {
     }
 }
 
-/// Represents the type of a group, which can be one of three variants: `Exact`, `AtLeast`, or `AtMost`.
+/// Represents the type of a group, which can be one of five variants: `Exact`, `AtLeast`, `AtMost`, `Between` or `AllOrNone`.
 #[derive(Debug, Clone)]
 pub enum GroupType {
     /// Represents a group with an exact member count.
@@ -188,4 +249,38 @@ pub enum GroupType {
     AtLeast(usize),
     /// Represents a group with at most a certain number of members.
     AtMost(usize),
+    /// Represents a group with a member count between a minimum and a maximum, inclusive.
+    Between(usize, usize),
+    /// Represents a group in which either all members or none of them are set.
+    AllOrNone,
+}
+
+impl GroupType {
+    /// Checks if a number of members satisfies the group type.
+    ///
+    /// # Arguments
+    ///
+    /// - `count`: The number of members that are set.
+    /// - `total`: The total number of members, which is only used by `AllOrNone`.
+    pub fn is_valid_count(&self, count: usize, total: usize) -> bool {
+        match *self {
+            GroupType::Exact(expected) => count == expected,
+            GroupType::AtLeast(expected) => count >= expected,
+            GroupType::AtMost(expected) => count <= expected,
+            GroupType::Between(min, max) => min <= count && count <= max,
+            GroupType::AllOrNone => count == 0 || count == total,
+        }
+    }
+}
+
+impl Display for GroupType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupType::Exact(expected) => write!(f, "{} {expected}", Symbol::Exact),
+            GroupType::AtLeast(expected) => write!(f, "{} {expected}", Symbol::AtLeast),
+            GroupType::AtMost(expected) => write!(f, "{} {expected}", Symbol::AtMost),
+            GroupType::Between(min, max) => write!(f, "{} {min} and {max}", Symbol::Between),
+            GroupType::AllOrNone => write!(f, "{}", Symbol::AllOrNone),
+        }
+    }
 }
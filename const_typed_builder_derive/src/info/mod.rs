@@ -1,7 +1,11 @@
+mod constraint;
 mod container;
 mod field;
 mod group;
 
-pub use container::{Container, SolverKind};
-pub use field::{Field, FieldCollection, FieldKind, SetterKind, TrackedField, TrackedFieldKind};
+pub use constraint::{Constraint, ConstraintCollection, ConstraintKind, Expression};
+pub use container::{Container, ContainerSettings, SolverKind};
+pub use field::{
+    Field, FieldCollection, FieldKind, FieldSettings, SetterKind, TrackedField, TrackedFieldKind,
+};
 pub use group::{Group, GroupCollection, GroupType};
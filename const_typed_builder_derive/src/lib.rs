@@ -11,8 +11,8 @@ use quote::quote;
 use syn::DeriveInput;
 
 /// The `derive_builder` macro is used to automatically generate builder
-/// code for a struct. It takes a struct as input and generates a builder
-/// pattern implementation for that struct.
+/// code for a struct or an enum. It takes a struct as input and generates a builder
+/// pattern implementation for that struct. For enums a builder is generated for every variant.
 ///
 /// # Example
 ///
@@ -26,7 +26,7 @@ use syn::DeriveInput;
 ///
 /// This will generate a builder pattern for `MyStruct`, allowing you to
 /// construct instances of `MyStruct` with a fluent API.
-#[proc_macro_derive(Builder, attributes(builder, group, groups))]
+#[proc_macro_derive(Builder, attributes(builder, constraint, group, groups))]
 #[proc_macro_error]
 pub fn derive_builder(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse_macro_input!(input as DeriveInput);
@@ -49,7 +49,7 @@ pub fn derive_builder(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 ///
 /// An optional `TokenStream` representing the generated token stream.
 fn impl_my_derive(ast: &syn::DeriveInput) -> Option<TokenStream> {
-    let container_info = parser::ContainerParser::new().parse(ast)?;
-    let generator = Generator::new(&container_info);
+    let container_infos = parser::ContainerParser::new().parse(ast)?;
+    let generator = Generator::new(&container_infos);
     Some(generator.generate())
 }
@@ -1,20 +1,28 @@
-use super::{FieldParser, GroupParser};
+use super::{extract_ident, extract_vis, FieldParser, GroupParser};
 use crate::{
-    info::{Container, Field, FieldCollection, GroupCollection, SolverKind},
+    info::{
+        Constraint, ConstraintCollection, ConstraintKind, Container, ContainerSettings, Expression,
+        Field, FieldKind, GroupCollection, SolverKind,
+    },
     symbol::Symbol,
 };
 use proc_macro_error::{emit_call_site_error, emit_error, emit_warning};
-use std::str::FromStr;
+use std::{collections::BTreeSet, str::FromStr};
+use syn::spanned::Spanned;
 
 /// Represents the parser for struct generation.
-#[derive(Debug)]
+#[derive(Debug, Clone, Default)]
 pub struct ContainerParser {
     assume_mandatory: bool,
     assume_into: bool,
+    assume_maybe: bool,
+    assume_once: bool,
     /// A map of group names to their respective `GroupInfo`.
     groups: GroupCollection,
-    /// The solver used to find all possible valid combinations for the groups
-    solver_kind: SolverKind,
+    /// The expressions defined using `#[constraint(...)]`, which are resolved once the fields are parsed.
+    constraints: Vec<syn::Expr>,
+    /// The settings of the container, like the solver and the names of the generated structs.
+    settings: ContainerSettings,
 }
 
 impl ContainerParser {
@@ -29,8 +37,8 @@ impl ContainerParser {
     ///
     /// # Returns
     ///
-    /// A `syn::Result` indicating success or failure of attribute handling.
-    pub fn parse(mut self, ast: &syn::DeriveInput) -> Option<Container> {
+    /// A `Container` for a struct, or one `Container` per variant for an enum.
+    pub fn parse(mut self, ast: &syn::DeriveInput) -> Option<Vec<Container<'_>>> {
         let syn::DeriveInput {
             attrs,
             vis,
@@ -39,23 +47,100 @@ impl ContainerParser {
             data,
         } = ast;
 
-        attrs.iter().for_each(|attr| self.handle_attribute(attr));
-
-        let fields = self.handle_data(data)?;
+        match data {
+            syn::Data::Struct(syn::DataStruct { fields, .. }) => {
+                attrs.iter().for_each(|attr| self.handle_attribute(attr));
+                let mut fields = self.handle_fields(fields);
+                let constraints = self.handle_constraints(&mut fields);
+                self.check_settings(&fields);
+                Some(vec![Container::new(
+                    vis,
+                    generics,
+                    ident,
+                    None,
+                    self.groups,
+                    fields,
+                    self.settings,
+                )
+                .with_constraint_collection(constraints)])
+            }
+            syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+                attrs
+                    .iter()
+                    .for_each(|attr| self.handle_enum_attribute(attr));
+                // The name and visibility defined on the enum are used for the variant selector
+                self.settings.selector_name = self.settings.builder_name.take();
+                self.settings.selector_vis = self.settings.builder_vis.clone();
+                if let Some(data_name) = self.settings.data_name.take() {
+                    emit_error!(
+                        data_name, "The data struct can't be named on an enum";
+                        help = "Try defining it on the variant instead"
+                    );
+                }
+                if variants.is_empty() {
+                    emit_call_site_error!("Builder can't be derived for enums without variants");
+                    return None;
+                }
+                let containers = variants
+                    .iter()
+                    .map(|variant| {
+                        let mut parser = self.clone();
+                        variant
+                            .attrs
+                            .iter()
+                            .for_each(|attr| parser.handle_attribute(attr));
+                        let mut fields = parser.handle_fields(&variant.fields);
+                        let constraints = parser.handle_constraints(&mut fields);
+                        parser.check_settings(&fields);
+                        Container::new(
+                            vis,
+                            generics,
+                            ident,
+                            Some(&variant.ident),
+                            parser.groups,
+                            fields,
+                            parser.settings,
+                        )
+                        .with_constraint_collection(constraints)
+                    })
+                    .collect();
+                Some(containers)
+            }
+            syn::Data::Union(_) => {
+                emit_call_site_error!("Builder does not support unions",);
+                None
+            }
+        }
+    }
 
-        Some(Container::new(
-            vis,
-            generics,
-            ident,
-            self.groups,
-            fields,
-            self.solver_kind,
-        ))
+    /// Handles the parsing and processing of attributes applied to an enum.
+    ///
+    /// Only builder attributes are accepted here, they act as the defaults for every variant.
+    /// Groups have to be defined on the variants themselves.
+    ///
+    /// # Arguments
+    ///
+    /// - `attr`: A reference to the `syn::Attribute` representing the attribute applied to the enum.
+    fn handle_enum_attribute(&mut self, attr: &syn::Attribute) {
+        match attr.path().require_ident() {
+            Ok(ident) if Symbol::from_str(&ident.to_string()) == Ok(Symbol::Builder) => {
+                self.handle_attribute(attr)
+            }
+            Ok(ident) => emit_error!(
+                ident, format!("{ident} can't be used as an enum attribute");
+                help = "Try defining it on the variant instead"
+            ),
+            Err(err) => emit_error!(
+                attr.path(), "Can't parse attribute";
+                note = err
+            ),
+        }
     }
 
     /// Handles the parsing and processing of attributes applied to a struct.
     ///
     /// See the specific functions [`handle_attribute_builder`] and [`handle_attribute_group`] for more information.
+    /// The expressions of `#[constraint(...)]` are resolved by [`handle_constraints`] once the fields are parsed.
     ///
     /// /// # Arguments
     ///
@@ -90,6 +175,14 @@ impl ContainerParser {
                     GroupParser::new(&mut self.groups).parse(attr)
                 }
                 Symbol::Groups => GroupParser::new(&mut self.groups).parse(attr),
+                Symbol::Constraint => match attr.parse_args() {
+                    Ok(expr) => self.constraints.push(expr),
+                    Err(err) => emit_error!(
+                        &attr.meta, "Constraint not specified correctly";
+                        help = "Try defining it like #[{}({}(foo, {}(bar, baz)))]", Symbol::Constraint, Symbol::Any, Symbol::All;
+                        note = err
+                    ),
+                },
                 Symbol::Builder => self.handle_attribute_builder(attr),
                 symbol => {
                     emit_error!(
@@ -113,8 +206,34 @@ impl ContainerParser {
     /// - `#[builder(assume_mandatory)]`: Indicates that all fields in the struct should be assumed as mandatory.
     ///
     /// - `#[builder(solver = `solve_type`)]`: Specifies the solver type to be used for building the struct. The `solve_type` should be one of
-    ///   the predefined solver types, such as `brute_force` or `compiler`. If provided with an equals sign (e.g., `#[builder(solver = brute_force)]`),
-    ///   it sets the `solver_type` accordingly.
+    ///   the predefined solver types: `brute_force`, `compiler`, `counting` or `auto`. If provided with an equals sign (e.g., `#[builder(solver = brute_force)]`),
+    ///   it sets the `solver_type` accordingly. `auto` uses `brute_force` for a small number of grouped fields, and `counting` otherwise.
+    ///
+    /// - `#[builder(name = FooBuilder)]`: Sets the name of the generated builder struct. On an enum this sets the name of the variant selector.
+    ///
+    /// - `#[builder(data_name = FooData)]`: Sets the name of the generated data struct.
+    ///
+    /// - `#[builder(vis = "pub(crate)")]`: Sets the visibility of the generated builder struct, which defaults to the visibility of the struct.
+    ///   If the builder is less visible than the struct, `builder()` is an inherent method instead of an implementation of `Builder`.
+    ///
+    /// - `#[builder(module)]`: Generates the builder and data struct inside of a private module, and only re-exports the builder.
+    ///   This way the data struct can't be used to bypass the checks of the builder.
+    ///
+    /// - `#[builder(maybe)]`: Generates a setter that takes the inner type and a setter that takes an `Option` for all optional and
+    ///   mandatory fields of type `Option`, unless they're validated.
+    ///
+    /// - `#[builder(once)]`: Makes setting any optional field more than once a compile error, except for collections with a setter for each item.
+    ///
+    /// - `#[builder(setter_prefix = "with_")]`: Adds a prefix to the names of all setters, unless a setter is renamed.
+    ///
+    /// - `#[builder(validate = path::to::fn)]`: Validates the target before it's returned by the build method, which is renamed to `try_build`.
+    ///   The function receives a reference to the target and returns a `Result<(), E>`.
+    ///
+    /// - `#[builder(error = FooError)]`: Sets the error type returned by `try_build` and the validating setters of fields,
+    ///   which defaults to `Box<dyn std::error::Error + Send + Sync>`. The error of the validation function is converted using `From`.
+    ///
+    /// - `#[builder(build_fn(...))]`: Sets the name and visibility of the build method, and which additional build methods are generated.
+    ///   See [`handle_specifier_build_fn`] for more information.
     ///
     /// # Arguments
     ///
@@ -138,8 +257,16 @@ impl ContainerParser {
                         let syn::ExprPath { path, .. } = meta.value()?.parse()?;
                         match Symbol::from_str(&path.require_ident()?.to_string()) {
                             Ok(solver) => match solver {
-                                Symbol::BruteForce => self.solver_kind = SolverKind::BruteForce,
-                                Symbol::Compiler => self.solver_kind = SolverKind::Compiler,
+                                Symbol::BruteForce => {
+                                    self.settings.solver_kind = Some(SolverKind::BruteForce)
+                                }
+                                Symbol::Compiler => {
+                                    self.settings.solver_kind = Some(SolverKind::Compiler)
+                                }
+                                Symbol::Counting => {
+                                    self.settings.solver_kind = Some(SolverKind::Counting)
+                                }
+                                Symbol::Auto => self.settings.solver_kind = None,
                                 solver => {
                                     emit_error!(&path, format!("{solver} is not a solver type"))
                                 }
@@ -150,8 +277,60 @@ impl ContainerParser {
                             ),
                         }
                     }
+                    Symbol::Name => match extract_ident(&meta) {
+                        Ok(name) => self.settings.builder_name = Some(name),
+                        Err(err) => emit_error!(
+                            &meta.path, "Name of the builder not specified correctly";
+                            help = "Try defining it like #[{}({} = FooBuilder)]", Symbol::Builder, Symbol::Name;
+                            note = err
+                        ),
+                    },
+                    Symbol::DataName => match extract_ident(&meta) {
+                        Ok(name) => self.settings.data_name = Some(name),
+                        Err(err) => emit_error!(
+                            &meta.path, "Name of the data struct not specified correctly";
+                            help = "Try defining it like #[{}({} = FooData)]", Symbol::Builder, Symbol::DataName;
+                            note = err
+                        ),
+                    },
+                    Symbol::Vis => match extract_vis(&meta) {
+                        Ok(vis) => self.settings.builder_vis = Some(vis),
+                        Err(err) => emit_error!(
+                            &meta.path, "Visibility of the builder not specified correctly";
+                            help = "Try defining it like #[{}({} = \"pub(crate)\")]", Symbol::Builder, Symbol::Vis;
+                            note = err
+                        ),
+                    },
+                    Symbol::Module => self.settings.module = true,
+                    Symbol::SetterPrefix => match extract_ident(&meta) {
+                        Ok(prefix) => self.settings.setter_prefix = Some(prefix.to_string()),
+                        Err(err) => emit_error!(
+                            &meta.path, "Prefix of the setters not specified correctly";
+                            help = "Try defining it like #[{}({} = \"with_\")]", Symbol::Builder, Symbol::SetterPrefix;
+                            note = err
+                        ),
+                    },
+                    Symbol::BuildFn => self.handle_specifier_build_fn(&meta),
+                    Symbol::Validate => match meta.value().and_then(|value| value.parse()) {
+                        Ok(path) => self.settings.build_fn.validate = Some(path),
+                        Err(err) => emit_error!(
+                            &meta.path, "Validation function not specified correctly";
+                            help = "Try defining it like #[{}({} = validate_foo)]", Symbol::Builder, Symbol::Validate;
+                            note = err
+                        ),
+                    },
+                    Symbol::Error => match meta.value().and_then(|value| value.parse()) {
+                        Ok(ty) => self.settings.build_fn.error = Some(ty),
+                        Err(err) => emit_error!(
+                            &meta.path, "Error type not specified correctly";
+                            help = "Try defining it like #[{}({} = FooError)]", Symbol::Builder, Symbol::Error;
+                            note = err
+                        ),
+                    },
                     Symbol::AssumeMandatory => self.assume_mandatory = true,
                     Symbol::Into => self.assume_into = true,
+                    Symbol::Maybe => self.assume_maybe = true,
+                    Symbol::Once => self.assume_once = true,
                     symbol => {
                         emit_error!(
                             &attr.meta,
@@ -174,63 +353,303 @@ impl ContainerParser {
         })
     }
 
-    fn handle_data<'a>(&mut self, data: &'a syn::Data) -> Option<FieldCollection<'a>> {
-        match data {
-            syn::Data::Struct(syn::DataStruct { fields, .. }) => self.handle_fields(fields),
-            syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-                let _ = variants
-                    .iter()
-                    .map(|variant| self.handle_fields(&variant.fields));
-                emit_call_site_error!("Builder does not *yet* support enums",);
-                None
-            }
-            syn::Data::Union(_) => {
-                emit_call_site_error!("Builder does not support unions",);
-                None
+    /// Checks if the combination of settings, and the members of the groups, are valid. Will emit errors if invalid.
+    fn check_settings(&self, fields: &[Field]) {
+        let validated = self.settings.build_fn.validate.is_some()
+            || fields.iter().any(|field| field.validate().is_some());
+        if let (Some(error), false) = (&self.settings.build_fn.error, validated) {
+            emit_error!(
+                error, "An error type can only be used together with a validation function";
+                help = "Try adding #[{}({} = validate_foo)]", Symbol::Builder, Symbol::Validate
+            );
+        }
+        // Members that are found are associated with their group while parsing the fields
+        for group in self.groups.values() {
+            for member in group.members() {
+                if !fields.iter().any(|field| field.ident() == member) {
+                    emit_error!(
+                        member, format!("Unknown field `{member}` in group `{}`", group.ident());
+                        help = "Use the name of a field, or the name given to an unnamed field using #[{}({} = foo)]", Symbol::Builder, Symbol::Name
+                    );
+                }
             }
         }
     }
 
-    fn handle_fields<'a>(&mut self, fields: &'a syn::Fields) -> Option<Vec<Field<'a>>> {
-        match fields {
-            syn::Fields::Named(fields) => Some(self.handle_named_fields(fields)),
-            syn::Fields::Unnamed(fields) => {
-                emit_error!(fields, "Builder does not support unnamed fields");
-                None
+    /// Handles the parsing and processing of the `build_fn` specifier of a builder attribute.
+    ///
+    /// It supports the following specifiers:
+    ///
+    /// - `name = finish`: Sets the name of the build method, which defaults to `build`.
+    ///
+    /// - `vis = "pub(crate)"`: Sets the visibility of the build method, which defaults to `pub`.
+    ///
+    /// - `boxed`, `arc` and `into`: Generates the additional build methods `{name}_boxed`, `{name}_arc` and `{name}_into`,
+    ///   which return a `Box`, an `Arc` or any type that implements `From` for the target respectively.
+    ///
+    /// # Arguments
+    ///
+    /// - `meta`: A reference to the `syn::meta::ParseNestedMeta` of the `build_fn` specifier.
+    fn handle_specifier_build_fn(&mut self, meta: &syn::meta::ParseNestedMeta) {
+        meta.parse_nested_meta(|meta| {
+            let path_ident = meta.path.require_ident()?;
+            match Symbol::from_str(&path_ident.to_string()) {
+                Ok(symbol) => match symbol {
+                    Symbol::Name => match extract_ident(&meta) {
+                        Ok(name) => self.settings.build_fn.name = Some(name),
+                        Err(err) => emit_error!(
+                            &meta.path, "Name of the build method not specified correctly";
+                            help = "Try defining it like #[{}({}({} = finish))]", Symbol::Builder, Symbol::BuildFn, Symbol::Name;
+                            note = err
+                        ),
+                    },
+                    Symbol::Vis => match extract_vis(&meta) {
+                        Ok(vis) => self.settings.build_fn.vis = Some(vis),
+                        Err(err) => emit_error!(
+                            &meta.path, "Visibility of the build method not specified correctly";
+                            help = "Try defining it like #[{}({}({} = \"pub(crate)\"))]", Symbol::Builder, Symbol::BuildFn, Symbol::Vis;
+                            note = err
+                        ),
+                    },
+                    Symbol::Boxed => self.settings.build_fn.boxed = true,
+                    Symbol::Arc => self.settings.build_fn.arc = true,
+                    Symbol::Into => self.settings.build_fn.into = true,
+                    symbol => {
+                        emit_error!(
+                            &meta.path,
+                            format!("Specifier {symbol} can't be used for the build method")
+                        )
+                    }
+                },
+                Err(err) => emit_error!(
+                    &meta.path, "Unknown symbol";
+                    note = err
+                ),
             }
-            syn::Fields::Unit => Some(Vec::new()),
-        }
+            Ok(())
+        })
+        .unwrap_or_else(|err| {
+            emit_error!(
+                &meta.path, "Build method not specified correctly";
+                help = "Try defining it like #[{}({}({} = finish))]", Symbol::Builder, Symbol::BuildFn, Symbol::Name;
+                note = err
+            )
+        })
     }
 
-    fn handle_named_fields<'a>(&mut self, fields: &'a syn::FieldsNamed) -> Vec<Field<'a>> {
+    fn handle_fields<'a>(&mut self, fields: &'a syn::Fields) -> Vec<Field<'a>> {
         fields
-            .named
             .iter()
             .enumerate()
             .map(|(index, field)| {
-                let ident = field
-                    .ident
-                    .as_ref()
-                    .expect("FieldsNamed should have an ident");
                 FieldParser::new(
                     index,
                     self.assume_mandatory,
                     self.assume_into,
+                    self.assume_maybe,
+                    self.assume_once,
                     &mut self.groups,
                 )
-                .parse(ident, field)
+                .parse(field)
             })
             .collect::<Vec<_>>()
     }
-}
 
-impl Default for ContainerParser {
-    fn default() -> Self {
-        ContainerParser {
-            assume_mandatory: false,
-            assume_into: false,
-            groups: GroupCollection::new(),
-            solver_kind: SolverKind::BruteForce,
+    /// Resolves the constraints defined on the fields using `requires` and `conflicts_with`, and on the container using `#[constraint(...)]`.
+    /// The fields that are referenced by a constraint are tracked like grouped fields. Will emit errors if a constraint is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// - `fields`: The parsed fields of the container.
+    ///
+    /// # Returns
+    ///
+    /// The constraints on the fields.
+    fn handle_constraints(&self, fields: &mut [Field]) -> ConstraintCollection {
+        let mut constraints = ConstraintCollection::new();
+        for field in fields.iter() {
+            let requires = field
+                .requires()
+                .iter()
+                .map(|other| (ConstraintKind::Requires, other));
+            let conflicts_with = field
+                .conflicts_with()
+                .iter()
+                .map(|other| (ConstraintKind::ConflictsWith, other));
+            for (kind, other) in requires.chain(conflicts_with) {
+                let other_field = match fields
+                    .iter()
+                    .find(|other_field| other_field.ident() == other)
+                {
+                    Some(other_field) => other_field,
+                    None => {
+                        emit_error!(
+                            other, format!("Unknown field `{other}`");
+                            help = "Use the name of another field, or `_0`, `_1`, etc. for unnamed fields"
+                        );
+                        continue;
+                    }
+                };
+                if other_field.index() == field.index() {
+                    emit_error!(other, "A field can't be constrained by itself");
+                    continue;
+                }
+                // A field that requires and conflicts with the same field can never be set
+                let contradicts = constraints.iter().any(|constraint: &Constraint| {
+                    constraint.kind() != kind
+                        && constraint.indices().contains(&field.index())
+                        && constraint.indices().contains(&other_field.index())
+                });
+                if contradicts {
+                    emit_error!(
+                        other, format!("`{}` and `{other}` can't both require and conflict with each other", field.ident());
+                        hint = "Remove either types of attribute"
+                    );
+                }
+                let field = (field.ident().clone(), field.index());
+                let other = (other.clone(), other_field.index());
+                constraints.push(match kind {
+                    ConstraintKind::Requires => Constraint::requires(field, other),
+                    ConstraintKind::ConflictsWith => Constraint::conflicts_with(field, other),
+                    ConstraintKind::Expression => unreachable!("Fields can't define expressions"),
+                });
+            }
+        }
+        constraints.extend(self.constraints.iter().filter_map(|expr| {
+            let expression = self.handle_constraint_expression(expr, fields)?;
+            Some(Constraint::expression(expression, expr.span()))
+        }));
+
+        let constrained_indices: BTreeSet<usize> = constraints
+            .iter()
+            .flat_map(Constraint::indices)
+            .collect();
+        // The ways in which a field can be referenced by a constraint, used in the hints of the errors below
+        let references = format!(
+            "#[{}({} = ...)], #[{}({} = ...)] or #[{}(...)]",
+            Symbol::Builder,
+            Symbol::Requires,
+            Symbol::Builder,
+            Symbol::ConflictsWith,
+            Symbol::Constraint
+        );
+        for field in fields
+            .iter_mut()
+            .filter(|field| constrained_indices.contains(&field.index()))
+        {
+            match field.kind() {
+                FieldKind::Mandatory => emit_error!(
+                    field.ident(), "Mandatory fields can't be part of a constraint";
+                    hint = "Make the field optional, or don't reference it in {}", references
+                ),
+                FieldKind::Skipped => emit_error!(
+                    field.ident(), "Skipped fields can't be part of a constraint";
+                    hint = "Don't reference the field in {}", references
+                ),
+                FieldKind::Optional | FieldKind::Grouped if !field.is_option_type() => {
+                    emit_error!(
+                        field.ty(),
+                        "Only fields of type `Option` can be part of a constraint"
+                    )
+                }
+                FieldKind::Optional | FieldKind::Grouped => {}
+            }
+            for specifier in field.explicit_specifiers() {
+                emit_error!(
+                    specifier, "Fields that are part of a constraint can't use this specifier";
+                    note = "These fields are tracked like the members of a group, so they are always set once and can't have a separate setter for an `Option`";
+                    hint = "Remove this specifier, or don't reference the field in {}", references
+                );
+            }
+            field.constrain();
+        }
+        constraints
+    }
+
+    /// Resolves an expression defined using `#[constraint(...)]`, in which fields are referenced by name.
+    /// Will emit errors if the expression is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// - `expr`: The expression as it's defined in the attribute.
+    /// - `fields`: The parsed fields of the container.
+    fn handle_constraint_expression(
+        &self,
+        expr: &syn::Expr,
+        fields: &[Field],
+    ) -> Option<Expression> {
+        match expr {
+            syn::Expr::Path(syn::ExprPath { path, .. }) => {
+                let ident = match path.require_ident() {
+                    Ok(ident) => ident,
+                    Err(err) => {
+                        emit_error!(path, "Field name not specified correctly"; note = err);
+                        return None;
+                    }
+                };
+                let field = fields.iter().find(|field| field.ident() == ident);
+                match (field, self.groups.get(&ident.to_string())) {
+                    (Some(field), _) => Some(Expression::Field(ident.clone(), field.index())),
+                    (None, Some(group)) => {
+                        emit_error!(
+                            ident, format!("The group `{ident}` can't be part of a constraint, as it's already enforced on its own");
+                            help = group.ident().span() => "Use the fields of the group instead"
+                        );
+                        None
+                    }
+                    (None, None) => {
+                        emit_error!(
+                            ident, format!("Unknown field `{ident}`");
+                            help = "Use the name of a field of the struct"
+                        );
+                        None
+                    }
+                }
+            }
+            syn::Expr::Call(syn::ExprCall { func, args, .. }) => {
+                let symbol = match func.as_ref() {
+                    syn::Expr::Path(syn::ExprPath { path, .. }) => path
+                        .get_ident()
+                        .and_then(|ident| Symbol::from_str(&ident.to_string()).ok()),
+                    _ => None,
+                };
+                let operands = args
+                    .iter()
+                    .map(|arg| self.handle_constraint_expression(arg, fields))
+                    .collect::<Option<Vec<_>>>()?;
+                match symbol {
+                    Some(Symbol::Not) => match <[Expression; 1]>::try_from(operands) {
+                        Ok([operand]) => Some(Expression::Not(Box::new(operand))),
+                        Err(_) => {
+                            emit_error!(
+                                func,
+                                format!("`{}` takes exactly one argument", Symbol::Not)
+                            );
+                            None
+                        }
+                    },
+                    Some(symbol @ (Symbol::All | Symbol::Any)) if operands.is_empty() => {
+                        emit_error!(func, format!("`{symbol}` takes at least one argument"));
+                        None
+                    }
+                    Some(Symbol::All) => Some(Expression::All(operands)),
+                    Some(Symbol::Any) => Some(Expression::Any(operands)),
+                    _ => {
+                        emit_error!(
+                            func, "Unknown operator";
+                            help = "Known operators are {}, {} and {}", Symbol::Any, Symbol::All, Symbol::Not
+                        );
+                        None
+                    }
+                }
+            }
+            expr => {
+                emit_error!(
+                    expr, "Can't parse constraint";
+                    help = "Try defining it like #[{}({}(foo, {}(bar, baz)))]", Symbol::Constraint, Symbol::Any, Symbol::All
+                );
+                None
+            }
         }
     }
 }
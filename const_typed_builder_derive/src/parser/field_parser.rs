@@ -1,19 +1,26 @@
+use super::{extract_ident, extract_vis};
 use crate::{
-    info::{Field, FieldKind, GroupCollection, SetterKind},
+    info::{Field, FieldKind, FieldSettings, GroupCollection, SetterKind},
     symbol::Symbol,
-    util::is_option,
+    util::{is_option, is_phantom_data, is_set_or_map},
 };
 use proc_macro_error::{emit_error, emit_warning};
+use quote::format_ident;
 use std::str::FromStr;
+use syn::{parse_quote, spanned::Spanned};
 
 /// Represents settings for struct field generation.
 #[derive(Debug)]
 pub struct FieldParser<'parser> {
     kind: Option<FieldKind>,
     setter_kind: Option<SetterKind>,
+    name: Option<syn::Ident>,
+    settings: FieldSettings,
     index: usize,
     assume_mandatory: bool,
     assume_into: bool,
+    assume_maybe: bool,
+    assume_once: bool,
     group_collection: &'parser mut GroupCollection,
 }
 
@@ -22,37 +29,69 @@ impl<'parser> FieldParser<'parser> {
         index: usize,
         assume_mandatory: bool,
         assume_into: bool,
+        assume_maybe: bool,
+        assume_once: bool,
         group_collection: &'parser mut GroupCollection,
     ) -> Self {
         Self {
             kind: None,
             setter_kind: None,
+            name: None,
+            settings: FieldSettings::default(),
             index,
             assume_mandatory,
             assume_into,
+            assume_maybe,
+            assume_once,
             group_collection,
         }
     }
 
-    pub fn parse<'ast>(mut self, ident: &'ast syn::Ident, field: &'ast syn::Field) -> Field<'ast> {
-        let syn::Field { ty, attrs, .. } = field;
-
-        if !is_option(ty) {
-            self.kind = Some(FieldKind::Mandatory); // If its not an option type it MUST always be mandatory
-        }
+    pub fn parse<'ast>(mut self, field: &'ast syn::Field) -> Field<'ast> {
+        let syn::Field {
+            ty, attrs, ident, ..
+        } = field;
 
         attrs
             .iter()
             .for_each(|attr: &syn::Attribute| self.handle_attribute(attr));
 
+        let field_name = ident
+            .clone()
+            .or_else(|| self.name.clone())
+            .unwrap_or_else(|| format_ident!("_{}", self.index));
+        self.handle_group_members(&field_name, ty);
+
         if self.kind.is_none() {
-            self.kind = if self.assume_mandatory {
+            self.kind = if is_phantom_data(ty) {
+                Some(FieldKind::Skipped)
+            } else if self.assume_mandatory || !is_option(ty) {
+                // If its not an option type, and it doesn't have a default, it MUST always be mandatory
                 Some(FieldKind::Mandatory)
             } else {
                 Some(FieldKind::Optional)
             };
         }
 
+        self.check_type(ty);
+        self.check_settings(ty);
+
+        // Only optional fields have to be tracked to be set once, collections can always be extended
+        if self.assume_once && self.settings.each.is_none() {
+            self.settings.once = self.kind == Some(FieldKind::Optional);
+        }
+
+        // Fields that can't have a separate setter for an `Option` are left out, instead of emitting an error
+        if self.assume_maybe && !self.settings.maybe {
+            self.settings.maybe = is_option(ty)
+                && matches!(self.kind, Some(FieldKind::Optional | FieldKind::Mandatory))
+                && self.settings.validate.is_none();
+        }
+
+        if self.kind == Some(FieldKind::Skipped) && self.settings.default.is_none() {
+            self.settings.default = Some(parse_quote!(::core::default::Default::default()));
+        }
+
         if self.setter_kind.is_none() {
             self.setter_kind = if self.assume_into {
                 Some(SetterKind::Into)
@@ -61,15 +100,187 @@ impl<'parser> FieldParser<'parser> {
             };
         }
 
+        let (ident, member) = match (ident, self.name) {
+            (Some(ident), None) => (ident.clone(), syn::Member::Named(ident.clone())),
+            (Some(ident), Some(name)) => {
+                emit_error!(
+                    name, "Only unnamed fields can be given a name";
+                    help = "Remove this specifier or rename the field `{}` itself", ident
+                );
+                (ident.clone(), syn::Member::Named(ident.clone()))
+            }
+            (None, name) => {
+                if let (Some(name), Some(rename)) = (&name, &self.settings.rename) {
+                    emit_error!(
+                        rename, "Can't rename the setter of a field that is already given a name";
+                        help = name.span() => "Remove either this specifier or `{}`, which names the setter `{}` already", Symbol::Name, name
+                    );
+                }
+                (
+                    name.unwrap_or_else(|| format_ident!("_{}", self.index)),
+                    syn::Member::Unnamed(self.index.into()),
+                )
+            }
+        };
+
         Field::new(
+            field,
             ident,
-            ty,
+            member,
             self.index,
             self.kind.unwrap(),
             self.setter_kind.unwrap(),
+            self.settings,
         )
     }
 
+    /// Checks if the kind of the field can be used with the type of the field. Will emit errors if invalid.
+    fn check_type(&self, ty: &syn::Type) {
+        if self.kind == Some(FieldKind::Skipped) {
+            return;
+        }
+        if self.settings.each.is_some() {
+            if is_option(ty) {
+                emit_error!(
+                    ty, "Fields of type `Option` can't be used as a collection";
+                    hint = "Remove the `Option` from the type of this field"
+                );
+            }
+            return;
+        }
+        if is_option(ty) {
+            if self.settings.default.is_some() {
+                emit_error!(
+                    ty, "Fields of type `Option` can't have a default";
+                    hint = "They already default to `None`, remove the default"
+                );
+            }
+            return;
+        }
+        match self.kind {
+            Some(FieldKind::Optional) if self.settings.default.is_none() => emit_error!(
+                ty, "Only fields of type `Option` can be optional without a default";
+                hint = "Try adding #[{}({})] to this field", Symbol::Builder, Symbol::Default
+            ),
+            Some(FieldKind::Grouped) => {
+                emit_error!(ty, "Only fields of type `Option` can be part of a group")
+            }
+            Some(FieldKind::Optional | FieldKind::Mandatory | FieldKind::Skipped) | None => {}
+        }
+    }
+
+    /// Checks if the combination of settings is valid. Will emit errors if invalid.
+    fn check_settings(&self, ty: &syn::Type) {
+        if self.settings.once {
+            if self.settings.each.is_some() {
+                emit_error!(
+                    ty, "Fields with a setter for each item can't be set once";
+                    hint = "Remove either types of attribute from this field"
+                );
+            }
+            if self.kind == Some(FieldKind::Skipped) {
+                emit_error!(
+                    ty, "Skipped fields can't be set";
+                    hint = "Remove either types of attribute from this field"
+                );
+            }
+        }
+        if self.settings.maybe {
+            if !is_option(ty) {
+                emit_error!(
+                    ty, "Only fields of type `Option` can have a separate setter for an `Option`";
+                    hint = "Remove #[{}({})] from this field", Symbol::Builder, Symbol::Maybe
+                );
+            }
+            if matches!(self.kind, Some(FieldKind::Grouped | FieldKind::Skipped)) {
+                emit_error!(
+                    ty, "Grouped and skipped fields can't have a separate setter for an `Option`";
+                    hint = "Remove #[{}({})] from this field", Symbol::Builder, Symbol::Maybe
+                );
+            }
+            if self.settings.validate.is_some() {
+                emit_error!(
+                    ty, "Validated fields can't have a separate setter for an `Option`";
+                    hint = "Remove either types of attribute from this field"
+                );
+            }
+        }
+        if self.settings.min.is_some() || self.settings.max.is_some() {
+            if self.settings.each.is_none() {
+                emit_error!(
+                    ty, "Only fields with a setter for each item can have a minimum or a maximum";
+                    help = "Try adding #[{}({} = item)] to this field", Symbol::Builder, Symbol::Each
+                );
+            }
+            if self.settings.default.is_some() {
+                emit_error!(
+                    ty, "Fields with a default can't have a minimum or a maximum";
+                    note = "The items of the default aren't counted by the builder";
+                    hint = "Remove either types of attribute from this field"
+                );
+            }
+            if is_set_or_map(ty) {
+                emit_error!(
+                    ty, "Sets and maps can't have a minimum or a maximum";
+                    note = "The builder counts the items that are added, and adding an item that's already in a set or map doesn't change its length"
+                );
+            }
+            match (self.settings.min, self.settings.max) {
+                (_, Some(0)) => emit_error!(ty, "The maximum has to be at least 1"),
+                (Some(min), Some(max)) if min > max => emit_error!(
+                    ty, "The minimum can't be larger than the maximum";
+                    note = format!("Minimum: {min}, maximum: {max}")
+                ),
+                _ => {}
+            }
+        }
+        if let Some(each) = &self.settings.each {
+            if self.settings.validate.is_some() {
+                emit_error!(
+                    each, "Fields with a setter for each item can't be validated";
+                    hint = "Remove either types of attribute from this field"
+                );
+            }
+            if self.settings.propagate.is_some() {
+                emit_error!(
+                    each, "Fields with a setter for each item can't be propagated";
+                    hint = "Remove either types of attribute from this field"
+                );
+            }
+            if matches!(
+                self.setter_kind,
+                Some(SetterKind::AsRef | SetterKind::AsMut)
+            ) {
+                emit_error!(
+                    each, "Fields with a setter for each item can only use the standard or the into setter";
+                    hint = "Remove either types of attribute from this field"
+                );
+            }
+        }
+        if self.settings.propagate.is_some()
+            && matches!(
+                self.setter_kind,
+                Some(SetterKind::AsRef | SetterKind::AsMut)
+            )
+        {
+            emit_error!(
+                ty, "Propagated fields can only use the standard or the into setter";
+                hint = "Remove either types of attribute from this field"
+            );
+        }
+        match (&self.settings.validate, &self.settings.error) {
+            (Some(validate), _) if self.kind == Some(FieldKind::Skipped) => emit_error!(
+                validate, "Skipped fields can't be validated";
+                hint = "Remove either types of attribute from this field"
+            ),
+            (None, Some(error)) => emit_error!(
+                error, "An error type can only be used together with a validation function";
+                help = "Try adding #[{}({} = validate_foo)]", Symbol::Builder, Symbol::Validate
+            ),
+            _ => {}
+        }
+    }
+
     /// Handles the parsing and processing of a builder attribute applied to a field.
     ///
     /// This method is responsible for interpreting the meaning of a builder attribute and updating the
@@ -81,16 +292,53 @@ impl<'parser> FieldParser<'parser> {
     /// - `#[builder(optional)]`: Marks the field as optional, meaning it does not have to be set during
     ///   the builder construction.
     ///
-    /// - `#[builder(skipped)]`: Marks the field as skipped, meaning it can't be set during
-    ///   the builder construction.
+    /// - `#[builder(default)]` or `#[builder(default = expr)]`: Marks the field as optional, the field will be set to
+    ///   `Default::default()` or `expr` if it's not set during the builder construction.
+    ///
+    /// - `#[builder(skip)]` or `#[builder(skip = expr)]`: Marks the field as skipped, meaning it can't be set during
+    ///   the builder construction. The field will be set to `Default::default()` or `expr` respectively.
+    ///   Fields of type `PhantomData` are skipped by default.
+    ///
+    /// - `#[builder(each = item)]`: Generates a setter called `item` that adds a single item to the collection, which starts out empty
+    ///   or with the default of the field. The field is optional, and the setter for the whole collection is only generated if its name differs.
+    ///
+    /// - `#[builder(min = 1, max = 3)]`: Sets the minimum and maximum number of items that have to be added using the setter for each item.
+    ///   The number of items is tracked by the builder, so the build method is only available if it's within these bounds.
+    ///   The builder counts the calls of the setter, so fields with a default, sets and maps can't have a minimum or a maximum.
     ///
     /// - `#[builder(group = group_name)]`: Associates the field with a group named `group_name`. Fields in the same group
     ///   are treated as a unit, and at least one of them must be set during builder construction. If the field is marked as mandatory,
     ///   it cannot be part of a group. This attribute allows specifying the group name both as an identifier (e.g., `group = my_group`)
     ///   and as a string (e.g., `group = "my_group"`).
+    ///   Fields can also be listed as members where the group is defined, like `#[groups(my_group = single(foo, bar))]`.
+    ///
+    /// - `#[builder(name = foo)]`: Sets the name of the setter of an unnamed field, which defaults to `_0`, `_1`, etc.
+    ///
+    /// - `#[builder(rename = foo)]`: Sets the name of the setter, which defaults to the name of the field with the setter prefix of the container.
+    ///   Unlike `name`, it only changes the setter, so the two can't be combined on an unnamed field.
+    ///
+    /// - `#[builder(vis = "pub(crate)")]`: Sets the visibility of the setter, which defaults to the visibility of the field.
     ///
-    /// - `#[builder(propagate)]`: Indicates that the field should propagate its value when the builder is constructed. If this attribute
-    ///   is present, the field's value will be copied or moved to the constructed object when the builder is used to build the object.
+    /// - `#[builder(validate = path::to::fn)]`: Validates the value of the field when it's set. The setter is called `try_{field}`
+    ///   and returns a `Result`. The function receives a reference to the value and returns a `Result<(), E>`.
+    ///
+    /// - `#[builder(error = FooError)]`: Sets the error type returned by the validating setter, which defaults to the error type of the container.
+    ///
+    /// - `#[builder(maybe)]`: Generates a setter that takes the inner type of a field of type `Option`, and a setter called `maybe_{field}`
+    ///   that takes an `Option`. Without it, only optional fields take an `Option`.
+    ///
+    /// - `#[builder(once)]`: Makes setting the field more than once a compile error. Mandatory and grouped fields can always only be set once,
+    ///   for optional fields this adds them to the type state of the builder.
+    ///
+    /// - `#[builder(propagate)]`: Generates a setter called `{field}_with` next to the normal setter, which takes a closure that receives the builder
+    ///   of the field's type. The suffix can be changed using `#[builder(propagate = suffix)]`.
+    ///
+    /// - `#[builder(requires = other)]`: The field `other` has to be set if this field is set, which is checked when the target is built.
+    ///
+    /// - `#[builder(conflicts_with = other)]`: The field `other` can't be set if this field is set, and vice versa.
+    ///   The setter of either field is no longer available once the other field is set.
+    ///
+    /// Fields that are part of a constraint are tracked like grouped fields, so they have to be of type `Option`, and can't use `maybe` or `once`.
     ///
     /// # Arguments
     ///
@@ -140,15 +388,96 @@ impl<'parser> FieldParser<'parser> {
 
             match Symbol::from_str(&path_ident.to_string()) {
                 Ok(symbol) => match symbol {
-                    Symbol::Skip => self.handle_attribute_skip(path_ident),
+                    Symbol::Skip => self.handle_attribute_skip(&meta),
                     Symbol::Mandatory => self.handle_attribute_mandatory(path_ident),
                     Symbol::Optional => self.handle_attribute_optional(path_ident),
+                    Symbol::Default => self.handle_attribute_default(&meta),
+                    Symbol::Each => self.handle_attribute_each(&meta),
+                    Symbol::Min => match meta.value().and_then(|value| value.parse::<syn::LitInt>()) {
+                        Ok(min) => match min.base10_parse() {
+                            Ok(min) => self.settings.min = Some(min),
+                            Err(err) => emit_error!(min, "Minimum is not a valid number"; note = err),
+                        },
+                        Err(err) => emit_error!(
+                            &meta.path, "Minimum not specified correctly";
+                            help = "Try defining it like #[{}({} = 1)]", Symbol::Builder, Symbol::Min;
+                            note = err
+                        ),
+                    },
+                    Symbol::Max => match meta.value().and_then(|value| value.parse::<syn::LitInt>()) {
+                        Ok(max) => match max.base10_parse() {
+                            Ok(max) => self.settings.max = Some(max),
+                            Err(err) => emit_error!(max, "Maximum is not a valid number"; note = err),
+                        },
+                        Err(err) => emit_error!(
+                            &meta.path, "Maximum not specified correctly";
+                            help = "Try defining it like #[{}({} = 3)]", Symbol::Builder, Symbol::Max;
+                            note = err
+                        ),
+                    },
                     Symbol::Group => self.handle_attribute_group(&meta),
-                    Symbol::Propagate => self.handle_setter_kind(SetterKind::Propagate, path_ident),
+                    Symbol::Name => self.handle_attribute_name(&meta),
+                    Symbol::Rename => match extract_ident(&meta) {
+                        Ok(rename) => self.settings.rename = Some(rename),
+                        Err(err) => emit_error!(
+                            &meta.path, "Name of the setter not specified correctly";
+                            help = "Try defining it like #[{}({} = foo)]", Symbol::Builder, Symbol::Rename;
+                            note = err
+                        ),
+                    },
+                    Symbol::Vis => match extract_vis(&meta) {
+                        Ok(vis) => self.settings.vis = Some(vis),
+                        Err(err) => emit_error!(
+                            &meta.path, "Visibility of the setter not specified correctly";
+                            help = "Try defining it like #[{}({} = \"pub(crate)\")]", Symbol::Builder, Symbol::Vis;
+                            note = err
+                        ),
+                    },
+                    Symbol::Validate => match meta.value().and_then(|value| value.parse()) {
+                        Ok(path) => self.settings.validate = Some(path),
+                        Err(err) => emit_error!(
+                            &meta.path, "Validation function not specified correctly";
+                            help = "Try defining it like #[{}({} = validate_foo)]", Symbol::Builder, Symbol::Validate;
+                            note = err
+                        ),
+                    },
+                    Symbol::Error => match meta.value().and_then(|value| value.parse()) {
+                        Ok(ty) => self.settings.error = Some(ty),
+                        Err(err) => emit_error!(
+                            &meta.path, "Error type not specified correctly";
+                            help = "Try defining it like #[{}({} = FooError)]", Symbol::Builder, Symbol::Error;
+                            note = err
+                        ),
+                    },
+                    Symbol::Propagate => self.handle_attribute_propagate(&meta),
                     Symbol::AsRef => self.handle_setter_kind(SetterKind::AsRef, path_ident),
                     Symbol::AsMut => self.handle_setter_kind(SetterKind::AsMut, path_ident),
                     Symbol::Into => self.handle_setter_kind(SetterKind::Into, path_ident),
                     Symbol::Standard => self.handle_setter_kind(SetterKind::Standard, path_ident),
+                    Symbol::Maybe => {
+                        self.settings.maybe = true;
+                        self.settings.explicit.push(meta.path.clone());
+                    }
+                    Symbol::Once => {
+                        self.settings.once = true;
+                        self.settings.explicit.push(meta.path.clone());
+                    }
+                    Symbol::Requires => match extract_ident(&meta) {
+                        Ok(other) => self.settings.requires.push(other),
+                        Err(err) => emit_error!(
+                            &meta.path, "Required field not specified correctly";
+                            help = "Try defining it like #[{}({} = foo)]", Symbol::Builder, Symbol::Requires;
+                            note = err
+                        ),
+                    },
+                    Symbol::ConflictsWith => match extract_ident(&meta) {
+                        Ok(other) => self.settings.conflicts_with.push(other),
+                        Err(err) => emit_error!(
+                            &meta.path, "Conflicting field not specified correctly";
+                            help = "Try defining it like #[{}({} = foo)]", Symbol::Builder, Symbol::ConflictsWith;
+                            note = err
+                        ),
+                    },
                     symbol => {
                         emit_error!(&attr.meta, format!("Specifier {symbol} can't be used here"))
                     }
@@ -176,22 +505,37 @@ impl<'parser> FieldParser<'parser> {
         }
     }
 
-    fn handle_attribute_skip(&mut self, ident: &syn::Ident) {
+    fn handle_attribute_skip(&mut self, meta: &syn::meta::ParseNestedMeta) {
+        if meta.input.peek(syn::Token![=]) {
+            match meta.value().and_then(|value| value.parse::<syn::Expr>()) {
+                Ok(expr) => {
+                    if self.settings.default.replace(expr).is_some() {
+                        emit_error!(meta.path, "Value of skipped field defined multiple times");
+                    }
+                }
+                Err(err) => emit_error!(
+                    meta.path, "Value of skipped field not specified correctly";
+                    help = "Try defining it like #[{}({} = foo)]", Symbol::Builder, Symbol::Skip;
+                    note = err
+                ),
+            }
+        }
+
         match self.kind {
             None => self.kind = Some(FieldKind::Skipped),
             Some(FieldKind::Optional) => emit_error!(
-                ident, "Can't define field as skipped as its already defined as optional";
+                meta.path, "Can't define field as skipped as its already defined as optional";
                 hint = "Remove either types of attribute from this field"
             ),
             Some(FieldKind::Skipped) => {
-                emit_warning!(ident, "Defined field as skipped multiple times")
+                emit_warning!(meta.path, "Defined field as skipped multiple times")
             }
             Some(FieldKind::Mandatory) => emit_error!(
-                ident, "Can't define field as skipped as its already defined as mandatory";
+                meta.path, "Can't define field as skipped as its already defined as mandatory";
                 hint = "Remove either types of attribute from this field"
             ),
             Some(FieldKind::Grouped) => emit_error!(
-                ident, "Can't define field as skipped when its also part of a group";
+                meta.path, "Can't define field as skipped when its also part of a group";
                 hint = "Remove either types of attribute from this field"
             ),
         }
@@ -239,6 +583,168 @@ impl<'parser> FieldParser<'parser> {
         }
     }
 
+    fn handle_attribute_default(&mut self, meta: &syn::meta::ParseNestedMeta) {
+        let default = if meta.input.peek(syn::Token![=]) {
+            match meta.value().and_then(|value| value.parse::<syn::Expr>()) {
+                Ok(expr) => expr,
+                Err(err) => {
+                    emit_error!(
+                        meta.path, "Default value not specified correctly";
+                        help = "Try defining it like #[{}({} = foo)]", Symbol::Builder, Symbol::Default;
+                        note = err
+                    );
+                    return;
+                }
+            }
+        } else {
+            parse_quote!(::core::default::Default::default())
+        };
+
+        if self.settings.default.replace(default).is_some() {
+            emit_error!(meta.path, "Default defined multiple times");
+        }
+
+        match self.kind {
+            None => self.kind = Some(FieldKind::Optional),
+            Some(FieldKind::Optional) => {}
+            Some(FieldKind::Skipped) => emit_error!(
+                meta.path, "Can't define a default for a field that's defined as skipped";
+                hint = "Remove either types of attribute from this field"
+            ),
+            Some(FieldKind::Mandatory) => emit_error!(
+                meta.path, "Can't define a default for a field that's defined as mandatory";
+                hint = "Remove either types of attribute from this field"
+            ),
+            Some(FieldKind::Grouped) => emit_error!(
+                meta.path, "Can't define a default for a field that's part of a group";
+                hint = "Remove either types of attribute from this field"
+            ),
+        }
+    }
+
+    fn handle_attribute_each(&mut self, meta: &syn::meta::ParseNestedMeta) {
+        match extract_ident(meta) {
+            Ok(each) => {
+                if self.settings.each.replace(each).is_some() {
+                    emit_error!(meta.path, "Setter for each item defined multiple times");
+                }
+            }
+            Err(err) => {
+                emit_error!(
+                    meta.path, "Setter for each item not specified correctly";
+                    help = "Try defining it like #[{}({} = foo)]", Symbol::Builder, Symbol::Each;
+                    note = err
+                );
+                return;
+            }
+        }
+
+        match self.kind {
+            None => self.kind = Some(FieldKind::Optional),
+            Some(FieldKind::Optional) => {}
+            Some(FieldKind::Skipped) => emit_error!(
+                meta.path, "Can't define a setter for each item for a field that's defined as skipped";
+                hint = "Remove either types of attribute from this field"
+            ),
+            Some(FieldKind::Mandatory) => emit_error!(
+                meta.path, "Can't define a setter for each item for a field that's defined as mandatory";
+                hint = "Remove either types of attribute from this field"
+            ),
+            Some(FieldKind::Grouped) => emit_error!(
+                meta.path, "Can't define a setter for each item for a field that's part of a group";
+                hint = "Remove either types of attribute from this field"
+            ),
+        }
+    }
+
+    fn handle_attribute_propagate(&mut self, meta: &syn::meta::ParseNestedMeta) {
+        let suffix = if meta.input.peek(syn::Token![=]) {
+            match extract_ident(meta) {
+                Ok(suffix) => suffix,
+                Err(err) => {
+                    emit_error!(
+                        meta.path, "Suffix of the propagating setter not specified correctly";
+                        help = "Try defining it like #[{}({} = with)]", Symbol::Builder, Symbol::Propagate;
+                        note = err
+                    );
+                    return;
+                }
+            }
+        } else {
+            format_ident!("with")
+        };
+
+        if self.settings.propagate.replace(suffix).is_some() {
+            emit_error!(meta.path, "Propagation defined multiple times");
+        }
+    }
+
+    fn handle_attribute_name(&mut self, meta: &syn::meta::ParseNestedMeta) {
+        match extract_ident(meta) {
+            Ok(name) if self.name.is_some() => {
+                emit_error!(name, "Name defined multiple times")
+            }
+            Ok(name) => self.name = Some(name),
+            Err(err) => emit_error!(
+                meta.path, "Name not specified correctly";
+                help = "Try defining it like #[{}({} = foo)]", Symbol::Builder, Symbol::Name;
+                note = err
+            ),
+        }
+    }
+
+    /// Associates the field with the groups that declare it as a member, like `#[groups(foo = single(bar, baz))]`.
+    /// Will emit errors if the field can't be part of a group.
+    ///
+    /// # Arguments
+    ///
+    /// - `field_name`: The name of the field, which is the name of its setter.
+    /// - `ty`: The type of the field.
+    fn handle_group_members(&mut self, field_name: &syn::Ident, ty: &syn::Type) {
+        let mut declarations = Vec::new();
+        for group in self.group_collection.values_mut() {
+            let member = match group.members().iter().find(|member| *member == field_name) {
+                Some(member) => member.clone(),
+                None => continue,
+            };
+            if !is_option(ty) {
+                emit_error!(
+                    member, "Only fields of type `Option` can be members of a group";
+                    help = ty.span() => "The type of `{}` is defined here", field_name
+                );
+                continue;
+            }
+            if !group.associate(self.index) {
+                emit_warning!(
+                    member, "Multiple adds to the same group";
+                    help = "Remove either this member or the `{}` specifier of the field", Symbol::Group
+                );
+            }
+            declarations.push(member);
+        }
+
+        let member = match declarations.first() {
+            Some(member) => member,
+            None => return,
+        };
+        match self.kind {
+            None => self.kind = Some(FieldKind::Grouped),
+            Some(FieldKind::Optional) => emit_error!(
+                member, "Can't declare field as member of a group as its already defined as optional";
+                hint = "Remove either the member or the attribute of the field"
+            ),
+            Some(FieldKind::Skipped) => emit_error!(
+                member, "Can't declare field as member of a group as its already defined as skipped";
+                hint = "Remove either the member or the attribute of the field"
+            ),
+            Some(FieldKind::Mandatory) => emit_error!(
+                member, "Can't declare field as member of a group as its already defined as mandatory";
+                hint = "Remove either the member or the attribute of the field"
+            ),
+            Some(FieldKind::Grouped) => {}
+        }
+    }
+
     fn handle_attribute_group(&mut self, meta: &syn::meta::ParseNestedMeta) {
         match self.kind {
             None => self.kind = Some(FieldKind::Grouped),
@@ -256,7 +762,7 @@ impl<'parser> FieldParser<'parser> {
             ),
             Some(FieldKind::Grouped) => {}
         }
-        match self.extract_group_name(meta) {
+        match extract_ident(meta) {
             Ok(group_name) => {
                 if let Some(group) = self.group_collection.get_mut(&group_name.to_string()) {
                     if group.indices().contains(&self.index) {
@@ -278,17 +784,4 @@ impl<'parser> FieldParser<'parser> {
             }
         };
     }
-
-    fn extract_group_name(&self, meta: &syn::meta::ParseNestedMeta) -> syn::Result<syn::Ident> {
-        match meta.value()?.parse()? {
-            syn::Expr::Path(syn::ExprPath { path, .. }) => {
-                path.require_ident().map(|ident| ident.clone())
-            }
-            syn::Expr::Lit(syn::ExprLit {
-                lit: syn::Lit::Str(lit),
-                ..
-            }) => Ok(syn::Ident::new(lit.value().as_str(), lit.span())),
-            expr => Err(syn::Error::new_spanned(expr, "Unexpected expresion type")),
-        }
-    }
 }
@@ -2,7 +2,7 @@ use crate::{
     info::{Group, GroupCollection, GroupType},
     symbol::Symbol,
 };
-use proc_macro_error::emit_error;
+use proc_macro_error::{emit_error, emit_warning};
 use std::str::FromStr;
 
 pub struct GroupParser<'a> {
@@ -28,7 +28,7 @@ impl<'a> GroupParser<'a> {
                 }
             };
 
-            let group_type = match meta.value()?.parse()? {
+            let group = match meta.value()?.parse()? {
                 syn::Expr::Call(expr) => self.handle_group_call(&expr),
                 syn::Expr::Path(expr) => self.handle_group_path(&expr),
                 _ => {
@@ -40,8 +40,17 @@ impl<'a> GroupParser<'a> {
                 }
             };
 
-            if let Some(group_type) = group_type {
-                if let Some(earlier_definition) = self.groups.insert(group_name.to_string(), Group::new(group_name.clone(), group_type)) {
+            if let Some((group_type, members)) = group {
+                let mut group = Group::new(group_name.clone(), group_type);
+                for member in members {
+                    if !group.declare_member(member.clone()) {
+                        emit_warning!(
+                            member, "Member listed multiple times";
+                            help = "Remove this member"
+                        );
+                    }
+                }
+                if let Some(earlier_definition) = self.groups.insert(group_name.to_string(), group) {
                     let earlier_span = earlier_definition.ident().span();
                     emit_error!(
                         &group_name, "Group defined multiple times";
@@ -58,7 +67,9 @@ impl<'a> GroupParser<'a> {
         ));
     }
 
-    fn handle_group_call(&self, expr: &syn::ExprCall) -> Option<GroupType> {
+    /// Parses a group type with arguments. The integer literals are the bounds of the group type,
+    /// and can be followed by the names of the fields that are members of the group, like `at_least(1, foo, bar)`.
+    fn handle_group_call(&self, expr: &syn::ExprCall) -> Option<(GroupType, Vec<syn::Ident>)> {
         let syn::ExprCall { func, args, .. } = expr;
 
         let type_ident = match func.as_ref() {
@@ -82,44 +93,75 @@ impl<'a> GroupParser<'a> {
             }
         };
 
-        if args.len() != 1 {
-            emit_error!(func, "Group needs exactly one integer literal as argument");
-            return None;
-        }
-
-        let group_argument = match args.first().unwrap() {
-            syn::Expr::Lit(syn::ExprLit {
-                lit: syn::Lit::Int(val),
-                ..
-            }) => val.base10_parse::<usize>().ok(),
-            _ => None,
-        };
-
-        let group_argument = match group_argument {
-            Some(lit) => lit,
-            None => {
-                emit_error!(args, "Can't parse argument");
-                return None;
+        let mut group_arguments = Vec::with_capacity(args.len());
+        let mut members = Vec::new();
+        for arg in args {
+            match arg {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(val),
+                    ..
+                }) if members.is_empty() => match val.base10_parse::<usize>() {
+                    Ok(lit) => group_arguments.push(lit),
+                    Err(err) => {
+                        emit_error!(arg, "Can't parse argument"; note = err);
+                        return None;
+                    }
+                },
+                syn::Expr::Path(syn::ExprPath { path, .. }) => match path.require_ident() {
+                    Ok(ident) => members.push(ident.clone()),
+                    Err(err) => {
+                        emit_error!(
+                            arg, "Member name is not specified correctly";
+                            help = "Use the name of a field of the builder";
+                            note = err
+                        );
+                        return None;
+                    }
+                },
+                _ => {
+                    emit_error!(
+                        arg, "Can't parse argument";
+                        help = "Arguments are integer literals followed by the names of the members, like `{}(1, foo, bar)`", Symbol::AtLeast
+                    );
+                    return None;
+                }
             }
-        };
+        }
 
-        match Symbol::from_str(&type_ident.to_string()) {
+        let group_type = match Symbol::from_str(&type_ident.to_string()) {
             Ok(symbol) => match symbol {
-                Symbol::AtLeast => Some(GroupType::AtLeast(group_argument)),
-                Symbol::AtMost => Some(GroupType::AtMost(group_argument)),
-                Symbol::Exact => Some(GroupType::Exact(group_argument)),
-                Symbol::Single => {
+                Symbol::AtLeast | Symbol::AtMost | Symbol::Exact if group_arguments.len() != 1 => {
+                    emit_error!(func, "Group needs exactly one integer literal as argument");
+                    None
+                }
+                Symbol::AtLeast => Some(GroupType::AtLeast(group_arguments[0])),
+                Symbol::AtMost => Some(GroupType::AtMost(group_arguments[0])),
+                Symbol::Exact => Some(GroupType::Exact(group_arguments[0])),
+                Symbol::Between => match group_arguments[..] {
+                    [min, max] => Some(GroupType::Between(min, max)),
+                    _ => {
+                        emit_error!(
+                            func,
+                            "Group needs exactly two integer literals as arguments";
+                            help = "Try to define it like `{}(1, 2)`", Symbol::Between
+                        );
+                        None
+                    }
+                },
+                Symbol::Single | Symbol::AllOrNone if !group_arguments.is_empty() => {
                     emit_error!(
                         args,
-                        "`{}` is the only group type that doesn't take any arguments", Symbol::Single;
-                        help = "`{}` is shorthand for {}(1)", Symbol::Single, Symbol::Exact
+                        "`{}` doesn't take any integer literals as arguments", symbol;
+                        help = "Try `{}` or `{}(foo, bar)` instead", symbol, symbol
                     );
                     None
                 }
+                Symbol::Single => Some(GroupType::Exact(1)),
+                Symbol::AllOrNone => Some(GroupType::AllOrNone),
                 symbol => {
                     emit_error!(
                         type_ident, format!("{symbol} is an unknown group type");
-                        help = "Known group types are {}, {} and {}", Symbol::Single, Symbol::AtLeast, Symbol::AtMost
+                        help = "Known group types are {}, {}, {}, {}, {} and {}", Symbol::Single, Symbol::Exact, Symbol::AtLeast, Symbol::AtMost, Symbol::Between, Symbol::AllOrNone
                     );
                     None
                 }
@@ -131,10 +173,11 @@ impl<'a> GroupParser<'a> {
                 );
                 None
             }
-        }
+        };
+        group_type.map(|group_type| (group_type, members))
     }
 
-    fn handle_group_path(&self, expr: &syn::ExprPath) -> Option<GroupType> {
+    fn handle_group_path(&self, expr: &syn::ExprPath) -> Option<(GroupType, Vec<syn::Ident>)> {
         let syn::ExprPath { path, .. } = expr;
         let type_ident = match path.require_ident() {
             Ok(ident) => ident,
@@ -149,7 +192,8 @@ impl<'a> GroupParser<'a> {
         };
         match Symbol::from_str(&type_ident.to_string()) {
             Ok(symbol) => match symbol {
-                Symbol::Single => Some(GroupType::Exact(1)),
+                Symbol::Single => Some((GroupType::Exact(1), Vec::new())),
+                Symbol::AllOrNone => Some((GroupType::AllOrNone, Vec::new())),
                 Symbol::Exact | Symbol::AtLeast | Symbol::AtMost => {
                     emit_error!(
                         &expr,
@@ -158,10 +202,18 @@ impl<'a> GroupParser<'a> {
                     );
                     None
                 }
+                Symbol::Between => {
+                    emit_error!(
+                        &expr,
+                        "Missing arguments for group type";
+                        help = "Try `{}(1, 2)`, or any other pair of usize", &type_ident
+                    );
+                    None
+                }
                 symbol => {
                     emit_error!(
                         type_ident, format!("{symbol} is an unknown group type");
-                        help = "Known group types are {}, {} and {}", Symbol::Single, Symbol::AtLeast, Symbol::AtMost
+                        help = "Known group types are {}, {}, {}, {}, {} and {}", Symbol::Single, Symbol::Exact, Symbol::AtLeast, Symbol::AtMost, Symbol::Between, Symbol::AllOrNone
                     );
                     None
                 }
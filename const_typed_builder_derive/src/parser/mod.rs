@@ -5,3 +5,25 @@ mod group_parser;
 pub use container_parser::ContainerParser;
 use field_parser::FieldParser;
 use group_parser::GroupParser;
+
+/// Extracts an identifier from a specifier that is defined like `foo = bar` or `foo = "bar"`.
+fn extract_ident(meta: &syn::meta::ParseNestedMeta) -> syn::Result<syn::Ident> {
+    match meta.value()?.parse()? {
+        syn::Expr::Path(syn::ExprPath { path, .. }) => path.require_ident().cloned(),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => Ok(syn::Ident::new(lit.value().as_str(), lit.span())),
+        expr => Err(syn::Error::new_spanned(expr, "Unexpected expresion type")),
+    }
+}
+
+/// Extracts a visibility from a specifier that is defined like `foo = "pub(crate)"` or `foo = pub(crate)`.
+fn extract_vis(meta: &syn::meta::ParseNestedMeta) -> syn::Result<syn::Visibility> {
+    let value = meta.value()?;
+    if value.peek(syn::LitStr) {
+        value.parse::<syn::LitStr>()?.parse()
+    } else {
+        value.parse()
+    }
+}
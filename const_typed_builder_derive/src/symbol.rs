@@ -6,6 +6,7 @@ pub enum Symbol {
     // Top level attributes
     Builder,
     Groups,
+    Constraint,
 
     // Field kinds
     Group, // Deprecated as top level attribute
@@ -13,17 +14,40 @@ pub enum Symbol {
     Skip,
     Optional,
     AssumeMandatory,
+    Default,
+    Each,
+    Min,
+    Max,
+
+    // Names and visibility
+    Name,
+    DataName,
+    Vis,
+    Module,
+    SetterPrefix,
+    Rename,
+
+    // Build method
+    BuildFn,
+    Boxed,
+    Arc,
+    Validate,
+    Error,
 
     // Group kinds
     Single,
     AtLeast,
     AtMost,
     Exact,
+    Between,
+    AllOrNone,
 
     // Solver kinds
     Solver,
     BruteForce,
     Compiler,
+    Counting,
+    Auto,
 
     // Setter kinds
     Propagate,
@@ -33,4 +57,15 @@ pub enum Symbol {
     #[strum(serialize = "as_mut", serialize = "asmut")]
     AsMut,
     Standard,
+    Maybe,
+
+    // Type state
+    Once,
+
+    // Constraints
+    Requires,
+    ConflictsWith,
+    Any,
+    All,
+    Not,
 }
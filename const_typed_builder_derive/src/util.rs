@@ -13,6 +13,37 @@ pub fn is_option(ty: &syn::Type) -> bool {
     }
 }
 
+pub fn is_phantom_data(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        if type_path.qself.is_some() {
+            return false;
+        }
+        if let Some(segment) = type_path.path.segments.last() {
+            segment.ident == syn::Ident::new("PhantomData", segment.ident.span())
+        } else {
+            false
+        }
+    } else {
+        false
+    }
+}
+
+pub fn is_set_or_map(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        if type_path.qself.is_some() {
+            return false;
+        }
+        if let Some(segment) = type_path.path.segments.last() {
+            let ident = segment.ident.to_string();
+            ident.ends_with("Set") || ident.ends_with("Map")
+        } else {
+            false
+        }
+    } else {
+        false
+    }
+}
+
 pub fn inner_type(ty: &syn::Type) -> Option<&syn::Type> {
     let path = if let syn::Type::Path(type_path) = ty {
         if type_path.qself.is_some() {
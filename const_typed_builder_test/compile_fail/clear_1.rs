@@ -0,0 +1,9 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    pub struct Foo {
+        bar: Option<String>,
+        clear_bar: Option<String>,
+    }
+}
@@ -0,0 +1,12 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    pub struct Foo {
+        #[builder(conflicts_with = port)]
+        socket_path: Option<String>,
+        port: Option<u16>,
+    }
+
+    let foo = Foo::builder().socket_path("/tmp/foo".to_string()).port(8080).build();
+}
@@ -0,0 +1,13 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    #[constraint(any(all(bar, not(baz), not(qux)), all(not(bar), baz, qux)))]
+    pub struct Foo {
+        bar: Option<String>,
+        baz: Option<String>,
+        qux: Option<String>,
+    }
+
+    let foo = Foo::builder().baz("Hello".to_string()).build();
+}
@@ -0,0 +1,11 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    pub struct Foo {
+        #[builder(maybe, requires = baz)]
+        bar: Option<String>,
+        #[builder(once)]
+        baz: Option<String>,
+    }
+}
@@ -0,0 +1,14 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    #[groups(credentials = all_or_none)]
+    #[constraint(any(token, credentials))]
+    pub struct Foo {
+        token: Option<String>,
+        #[builder(group = credentials)]
+        user: Option<String>,
+        #[builder(group = credentials)]
+        password: Option<String>,
+    }
+}
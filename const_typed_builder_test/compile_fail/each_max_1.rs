@@ -0,0 +1,15 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    pub struct Foo {
+        #[builder(each = bar, max = 2)]
+        bars: Vec<String>,
+    }
+
+    let foo = Foo::builder()
+        .bar("Hello".to_string())
+        .bar("world".to_string())
+        .bar("!".to_string())
+        .build();
+}
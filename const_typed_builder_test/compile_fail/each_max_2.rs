@@ -0,0 +1,12 @@
+use const_typed_builder::Builder;
+use std::collections::HashSet;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    pub struct Foo {
+        #[builder(each = bar, max = 2, default = vec!["Hello".to_string()])]
+        bars: Vec<String>,
+        #[builder(each = baz, min = 1)]
+        bazs: HashSet<String>,
+    }
+}
@@ -0,0 +1,12 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    pub struct Foo {
+        #[builder(each = bar, min = 1)]
+        bars: Vec<String>,
+    }
+    
+    let foo = Foo::builder()
+        .build();
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, PartialEq, Builder)]
+    pub enum Message {
+        #[groups(auth = single)]
+        Login {
+            #[builder(group = auth)]
+            token: Option<String>,
+            #[builder(group = auth)]
+            password: Option<String>,
+        },
+        Logout,
+    }
+
+    let message = Message::builder().login().build();
+}
@@ -0,0 +1,11 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, PartialEq, Builder)]
+    pub enum Message {
+        Connect { host: String, port: Option<u16> },
+        Disconnect,
+    }
+
+    let message = Message::connect_builder().port(Some(8080)).build();
+}
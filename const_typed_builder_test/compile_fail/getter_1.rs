@@ -0,0 +1,11 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    pub struct Foo {
+        bar: String,
+    }
+
+    let builder = Foo::builder();
+    let bar = builder.bar_ref();
+}
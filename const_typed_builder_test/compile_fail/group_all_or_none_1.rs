@@ -0,0 +1,14 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    #[groups(quz = all_or_none)]
+    pub struct Foo {
+        #[builder(group = quz)]
+        bar: Option<String>,
+        #[builder(group = quz)]
+        baz: Option<String>,
+    }
+
+    let foo = Foo::builder().bar("Hello".to_string()).build();
+}
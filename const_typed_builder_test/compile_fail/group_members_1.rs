@@ -0,0 +1,9 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    #[groups(baz = single(bar, qux))]
+    pub struct Foo {
+        bar: Option<String>,
+    }
+}
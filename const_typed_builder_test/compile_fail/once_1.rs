@@ -0,0 +1,11 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    pub struct Foo {
+        #[builder(once)]
+        bar: Option<String>,
+    }
+
+    let foo = Foo::builder().bar(Some("Hello".to_string())).bar(Some("world!".to_string())).build();
+}
\ No newline at end of file
@@ -12,5 +12,5 @@ fn main() {
         baz: String,
     }
 
-    let foo = Foo::builder().bar(|builder| builder.build() ).build();
-}
\ No newline at end of file
+    let foo = Foo::builder().bar_with(|builder| builder).build();
+}
@@ -11,5 +11,5 @@ fn main() {
     pub struct Bar {
         baz: String,
     }
-    let foo = Foo::builder().bar(|builder| builder.baz("Hello world!".to_string()).build()).build();
-}
\ No newline at end of file
+    let foo = Foo::builder().bar_with(|builder| Some(builder.baz("Hello world!".to_string()))).build();
+}
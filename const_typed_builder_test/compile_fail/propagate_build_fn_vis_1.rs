@@ -0,0 +1,19 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, PartialEq, Builder)]
+    pub struct Foo {
+        #[builder(propagate)]
+        bar: Bar,
+    }
+
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(build_fn(vis = "pub(crate)"))]
+    pub struct Bar {
+        baz: String,
+    }
+
+    let foo = Foo::builder()
+        .bar_with(|builder| builder.baz("Hello world!".to_string()))
+        .build();
+}
@@ -0,0 +1,6 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, PartialEq, Builder)]
+    pub struct Foo(#[builder(name = bar, rename = set_bar)] String);
+}
@@ -0,0 +1,12 @@
+use const_typed_builder::Builder;
+
+fn main() {
+    #[derive(Debug, Default, PartialEq, Eq, Builder)]
+    pub struct Foo {
+        #[builder(requires = tls_cert)]
+        tls_key: Option<String>,
+        tls_cert: Option<String>,
+    }
+
+    let foo = Foo::builder().tls_key("key".to_string()).build();
+}
@@ -277,608 +277,2477 @@ mod test {
     }
 
     #[test]
-    fn group_multiple_member() {
+    fn group_solver_counting() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
         #[groups(baz = single)]
+        #[builder(solver = counting)]
         pub struct Foo {
             #[builder(group = baz)]
             bar: Option<String>,
             #[builder(group = baz)]
-            qux: Option<String>,
+            baz: Option<String>,
         }
 
         let expected = Foo {
             bar: Some("Hello world!".to_string()),
-            qux: None,
+            baz: None,
         };
         let foo = Foo::builder().bar("Hello world!".to_string()).build();
         assert_eq!(expected, foo);
-
-        let expected = Foo {
-            bar: None,
-            qux: Some("Hello world!".to_string()),
-        };
-        let foo = Foo::builder().qux("Hello world!".to_string()).build();
-        assert_eq!(expected, foo);
     }
 
     #[test]
-    fn group_and_mandatory() {
-        #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        #[groups(quz = single)]
+    fn group_solver_counting_overlapping() {
+        #[derive(Debug, Default, Clone, PartialEq, Eq, Builder)]
+        #[groups(quz = at_least(2), quux = at_most(1))]
+        #[builder(solver = counting)]
         pub struct Foo {
-            #[builder(group = quz)]
-            bar: Option<String>,
+            bar: String,
             #[builder(group = quz)]
             baz: Option<String>,
-            qux: String,
+            #[builder(group = quz, group = quux)]
+            qux: Option<String>,
+            #[builder(group = quz, group = quux)]
+            corge: Option<String>,
         }
 
-        let expected = Foo {
-            bar: Some("Hello".to_string()),
-            baz: None,
-            qux: "world!".to_string(),
-        };
-
         let foo = Foo::builder()
             .bar("Hello".to_string())
-            .qux("world!".to_string())
+            .baz("world".to_string())
+            .corge("!".to_string())
             .build();
-        assert_eq!(expected, foo);
-
-        let expected = Foo {
-            bar: None,
-            baz: Some("Hello".to_string()),
-            qux: "world!".to_string(),
-        };
+        assert_eq!(
+            foo,
+            Foo {
+                bar: "Hello".to_string(),
+                baz: Some("world".to_string()),
+                qux: None,
+                corge: Some("!".to_string()),
+            }
+        );
 
-        let foo = Foo::builder()
-            .qux("world!".to_string())
-            .baz("Hello".to_string())
-            .build();
-        assert_eq!(expected, foo);
+        let builder: FooBuilder<true, true, false, true> = foo.clone().try_into().unwrap();
+        let foo = builder.clear_corge().qux("?".to_string()).build();
+        assert_eq!(foo.qux, Some("?".to_string()));
+        assert_eq!(foo.corge, None);
     }
 
     #[test]
-    fn group_and_option_mandatory() {
+    fn group_between() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        #[groups(quz = single)]
+        #[groups(quz = between(1, 2))]
         pub struct Foo {
             #[builder(group = quz)]
             bar: Option<String>,
             #[builder(group = quz)]
             baz: Option<String>,
-            #[builder(mandatory)]
+            #[builder(group = quz)]
             qux: Option<String>,
         }
 
-        let expected = Foo {
-            bar: Some("Hello".to_string()),
-            baz: None,
-            qux: Some("world!".to_string()),
-        };
+        let foo = Foo::builder().bar("Hello".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: Some("Hello".to_string()),
+                baz: None,
+                qux: None,
+            }
+        );
 
         let foo = Foo::builder()
             .bar("Hello".to_string())
-            .qux("world!".to_string())
-            .build();
-        assert_eq!(expected, foo);
-
-        let expected = Foo {
-            bar: None,
-            baz: Some("Hello".to_string()),
-            qux: Some("world!".to_string()),
-        };
-
-        let foo = Foo::builder()
-            .qux("world!".to_string())
-            .baz("Hello".to_string())
+            .qux("world".to_string())
             .build();
-        assert_eq!(expected, foo);
+        assert_eq!(
+            foo,
+            Foo {
+                bar: Some("Hello".to_string()),
+                baz: None,
+                qux: Some("world".to_string()),
+            }
+        );
     }
 
     #[test]
-    fn group_at_least() {
+    fn group_all_or_none() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        #[groups(quz = at_least(2))]
+        #[groups(quz = all_or_none)]
         pub struct Foo {
-            #[builder(group = quz)]
-            bar: Option<String>,
+            bar: String,
             #[builder(group = quz)]
             baz: Option<String>,
             #[builder(group = quz)]
             qux: Option<String>,
         }
 
-        let expected = Foo {
-            bar: Some("Hello".to_string()),
-            baz: None,
-            qux: Some("world!".to_string()),
-        };
+        let foo = Foo::builder().bar("Hello".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: "Hello".to_string(),
+                baz: None,
+                qux: None,
+            }
+        );
 
         let foo = Foo::builder()
             .bar("Hello".to_string())
-            .qux("world!".to_string())
-            .build();
-        assert_eq!(expected, foo);
-
-        let expected = Foo {
-            bar: None,
-            baz: Some("Hello".to_string()),
-            qux: Some("world!".to_string()),
-        };
-
-        let foo = Foo::builder()
-            .qux("world!".to_string())
-            .baz("Hello".to_string())
-            .build();
-        assert_eq!(expected, foo);
-
-        let expected = Foo {
-            bar: Some("Hello".to_string()),
-            baz: Some("world".to_string()),
-            qux: Some("!".to_string()),
-        };
-
-        let foo = Foo::builder()
-            .qux("!".to_string())
             .baz("world".to_string())
-            .bar("Hello".to_string())
+            .qux("!".to_string())
             .build();
-        assert_eq!(expected, foo);
+        assert_eq!(
+            foo,
+            Foo {
+                bar: "Hello".to_string(),
+                baz: Some("world".to_string()),
+                qux: Some("!".to_string()),
+            }
+        );
     }
 
     #[test]
-    fn group_at_most() {
+    fn group_between_all_or_none_solver_compiler() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        #[groups(quz = at_most(2))]
+        #[groups(quz = between(1, 2), quux = all_or_none)]
+        #[builder(solver = compiler)]
         pub struct Foo {
             #[builder(group = quz)]
             bar: Option<String>,
             #[builder(group = quz)]
             baz: Option<String>,
-            #[builder(group = quz)]
+            #[builder(group = quz, group = quux)]
             qux: Option<String>,
+            #[builder(group = quux)]
+            corge: Option<String>,
         }
 
-        let expected = Foo {
-            bar: Some("Hello".to_string()),
-            baz: None,
-            qux: Some("world!".to_string()),
-        };
-
         let foo = Foo::builder()
             .bar("Hello".to_string())
-            .qux("world!".to_string())
-            .build();
-        assert_eq!(expected, foo);
-
-        let expected = Foo {
-            bar: None,
-            baz: Some("Hello".to_string()),
-            qux: Some("world!".to_string()),
-        };
-
-        let foo = Foo::builder()
-            .qux("world!".to_string())
-            .baz("Hello".to_string())
+            .qux("world".to_string())
+            .corge("!".to_string())
             .build();
-        assert_eq!(expected, foo);
-
-        let expected = Foo {
-            bar: None,
-            baz: Some("Hello world!".to_string()),
-            qux: None,
-        };
-        let foo = Foo::builder().baz("Hello world!".to_string()).build();
-        assert_eq!(expected, foo);
+        assert_eq!(
+            foo,
+            Foo {
+                bar: Some("Hello".to_string()),
+                baz: None,
+                qux: Some("world".to_string()),
+                corge: Some("!".to_string()),
+            }
+        );
     }
 
     #[test]
-    fn single_generic_added_default() {
+    fn group_between_all_or_none_solver_counting() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        pub struct Foo<A>
-        where
-            A: Default,
-        {
-            bar: A,
+        #[groups(quz = between(1, 2), quux = all_or_none)]
+        #[builder(solver = counting)]
+        pub struct Foo {
+            #[builder(group = quz)]
+            bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz, group = quux)]
+            qux: Option<String>,
+            #[builder(group = quux)]
+            corge: Option<String>,
         }
 
-        let expected = Foo::<String> {
-            bar: "Hello world!".to_string(),
-        };
-        let foo = Foo::<String>::builder()
-            .bar("Hello world!".to_string())
-            .build();
-        assert_eq!(expected, foo);
-
-        let foo: Foo<String> = Foo::builder().bar("Hello world!".to_string()).build();
-        assert_eq!(expected, foo);
+        let foo = Foo::builder().baz("Hello".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: None,
+                baz: Some("Hello".to_string()),
+                qux: None,
+                corge: None,
+            }
+        );
     }
 
     #[test]
-    fn single_generic_multiple_mandatory_added_default() {
+    fn constraint_requires() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        pub struct Foo<A>
-        where
-            A: Default,
-        {
-            bar: A,
-            baz: A,
+        pub struct Foo {
+            #[builder(requires = tls_cert)]
+            tls_key: Option<String>,
+            tls_cert: Option<String>,
         }
 
-        let expected = Foo::<String> {
-            bar: "Hello world!".to_string(),
-            baz: "Hello world!".to_string(),
-        };
-        let foo = Foo::<String>::builder()
-            .bar("Hello world!".to_string())
-            .baz("Hello world!".to_string())
-            .build();
-        assert_eq!(expected, foo);
+        let foo = Foo::builder().build();
+        assert_eq!(foo, Foo::default());
+
+        let foo = Foo::builder().tls_cert("cert".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo {
+                tls_key: None,
+                tls_cert: Some("cert".to_string()),
+            }
+        );
 
-        let foo: Foo<String> = Foo::builder()
-            .bar("Hello world!".to_string())
-            .baz("Hello world!".to_string())
+        let foo = Foo::builder()
+            .tls_key("key".to_string())
+            .tls_cert("cert".to_string())
             .build();
-        assert_eq!(expected, foo);
+        assert_eq!(
+            foo,
+            Foo {
+                tls_key: Some("key".to_string()),
+                tls_cert: Some("cert".to_string()),
+            }
+        );
     }
 
     #[test]
-    fn single_generic_multiple_mandatory() {
+    fn constraint_conflicts_with() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        pub struct Foo<A> {
-            bar: A,
-            baz: A,
+        pub struct Foo {
+            host: String,
+            #[builder(conflicts_with = port)]
+            socket_path: Option<String>,
+            port: Option<u16>,
         }
 
-        let expected = Foo::<String> {
-            bar: "Hello world!".to_string(),
-            baz: "Hello world!".to_string(),
-        };
-        let foo = Foo::<String>::builder()
-            .bar("Hello world!".to_string())
-            .baz("Hello world!".to_string())
+        let foo = Foo::builder()
+            .host("localhost".to_string())
+            .socket_path("/tmp/foo".to_string())
             .build();
-        assert_eq!(expected, foo);
+        assert_eq!(
+            foo,
+            Foo {
+                host: "localhost".to_string(),
+                socket_path: Some("/tmp/foo".to_string()),
+                port: None,
+            }
+        );
 
-        let foo: Foo<String> = Foo::builder()
-            .bar("Hello world!".to_string())
-            .baz("Hello world!".to_string())
+        let foo = Foo::builder()
+            .port(8080)
+            .host("localhost".to_string())
             .build();
-
-        assert_eq!(expected, foo);
+        assert_eq!(
+            foo,
+            Foo {
+                host: "localhost".to_string(),
+                socket_path: None,
+                port: Some(8080),
+            }
+        );
 
         let foo = Foo::builder()
-            .bar("Hello world!".to_string())
-            .baz("Hello world!".to_string())
+            .port(8080)
+            .clear_port()
+            .socket_path("/tmp/foo".to_string())
+            .host("localhost".to_string())
             .build();
-
-        assert_eq!(expected, foo);
+        assert_eq!(foo.socket_path, Some("/tmp/foo".to_string()));
+        assert_eq!(foo.port, None);
     }
 
     #[test]
-    fn multiple_generic_multiple_mandatory() {
-        #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        pub struct Foo<A, B> {
-            bar: A,
-            baz: B,
+    fn constraint_with_group() {
+        #[derive(Debug, Default, Clone, PartialEq, Eq, Builder)]
+        #[groups(auth = single)]
+        pub struct Foo {
+            #[builder(group = auth)]
+            token: Option<String>,
+            #[builder(group = auth, requires = user)]
+            password: Option<String>,
+            user: Option<String>,
         }
 
-        let expected = Foo::<String, &str> {
-            bar: "Hello world!".to_string(),
-            baz: "Hello world!",
-        };
-        let foo = Foo::<String, &str>::builder()
-            .bar("Hello world!".to_string())
-            .baz("Hello world!")
+        let foo = Foo::builder()
+            .password("hunter2".to_string())
+            .user("admin".to_string())
             .build();
-        assert_eq!(expected, foo);
+        assert_eq!(
+            foo,
+            Foo {
+                token: None,
+                password: Some("hunter2".to_string()),
+                user: Some("admin".to_string()),
+            }
+        );
 
-        let foo: Foo<String, &str> = Foo::builder()
-            .bar("Hello world!".to_string())
-            .baz("Hello world!")
-            .build();
-        assert_eq!(expected, foo);
+        let builder: FooBuilder<false, true, true> = foo.clone().try_into().unwrap();
+        assert_eq!(builder.build(), foo);
     }
 
     #[test]
-    fn multiple_generic_with_const_multiple_mandatory() {
+    fn constraint_solver_compiler() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        pub struct Foo<A, B, const C: usize> {
-            bar: A,
-            baz: B,
+        #[builder(solver = compiler)]
+        pub struct Foo {
+            #[builder(requires = tls_cert)]
+            tls_key: Option<String>,
+            tls_cert: Option<String>,
+            #[builder(conflicts_with = port)]
+            socket_path: Option<String>,
+            port: Option<u16>,
         }
 
-        let expected = Foo::<String, &str, 0> {
-            bar: "Hello world!".to_string(),
-            baz: "Hello world!",
-        };
-        let foo = Foo::<String, &str, 0>::builder()
-            .bar("Hello world!".to_string())
-            .baz("Hello world!")
+        let foo = Foo::builder()
+            .tls_key("key".to_string())
+            .tls_cert("cert".to_string())
+            .port(8080)
             .build();
-        assert_eq!(expected, foo);
+        assert_eq!(
+            foo,
+            Foo {
+                tls_key: Some("key".to_string()),
+                tls_cert: Some("cert".to_string()),
+                socket_path: None,
+                port: Some(8080),
+            }
+        );
     }
 
     #[test]
-    fn single_propagate() {
+    fn constraint_solver_counting() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[builder(solver = counting)]
         pub struct Foo {
-            #[builder(propagate)]
-            bar: Bar,
+            #[builder(requires = tls_cert)]
+            tls_key: Option<String>,
+            tls_cert: Option<String>,
+            #[builder(conflicts_with = port)]
+            socket_path: Option<String>,
+            port: Option<u16>,
         }
 
-        #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        pub struct Bar {
-            baz: String,
-        }
+        let foo = Foo::builder()
+            .tls_key("key".to_string())
+            .tls_cert("cert".to_string())
+            .socket_path("/tmp/foo".to_string())
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                tls_key: Some("key".to_string()),
+                tls_cert: Some("cert".to_string()),
+                socket_path: Some("/tmp/foo".to_string()),
+                port: None,
+            }
+        );
+    }
+
+    #[test]
+    fn constraint_expression() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[constraint(any(all(bar, not(baz), not(qux)), all(not(bar), baz, qux)))]
+        pub struct Foo {
+            bar: Option<String>,
+            baz: Option<String>,
+            qux: Option<String>,
+        }
+
+        let foo = Foo::builder().bar("Hello".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: Some("Hello".to_string()),
+                baz: None,
+                qux: None,
+            }
+        );
+
+        let foo = Foo::builder()
+            .baz("Hello".to_string())
+            .qux("world".to_string())
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: None,
+                baz: Some("Hello".to_string()),
+                qux: Some("world".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn constraint_expression_conflict() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[constraint(not(all(bar, baz)))]
+        pub struct Foo {
+            bar: Option<String>,
+            baz: Option<String>,
+        }
+
+        let foo = Foo::builder().baz("Hello".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: None,
+                baz: Some("Hello".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn constraint_expression_with_group() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(credentials = all_or_none)]
+        #[constraint(any(token, user))]
+        pub struct Foo {
+            token: Option<String>,
+            #[builder(group = credentials)]
+            user: Option<String>,
+            #[builder(group = credentials)]
+            password: Option<String>,
+        }
+
+        let foo = Foo::builder().token("abc".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo {
+                token: Some("abc".to_string()),
+                user: None,
+                password: None,
+            }
+        );
+
+        let foo = Foo::builder()
+            .user("admin".to_string())
+            .password("hunter2".to_string())
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                token: None,
+                user: Some("admin".to_string()),
+                password: Some("hunter2".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn constraint_expression_solver_compiler() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(quz = at_least(1))]
+        #[constraint(any(bar, all(baz, not(corge))))]
+        #[builder(solver = compiler)]
+        pub struct Foo {
+            bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
+            corge: Option<String>,
+        }
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .baz("world".to_string())
+            .corge("!".to_string())
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: Some("Hello".to_string()),
+                baz: Some("world".to_string()),
+                qux: None,
+                corge: Some("!".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn constraint_expression_solver_counting() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(quz = at_least(1))]
+        #[constraint(any(bar, all(baz, not(corge))))]
+        #[builder(solver = counting)]
+        pub struct Foo {
+            bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
+            corge: Option<String>,
+        }
+
+        let foo = Foo::builder().baz("Hello".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: None,
+                baz: Some("Hello".to_string()),
+                qux: None,
+                corge: None,
+            }
+        );
+    }
+
+    #[test]
+    fn group_members() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(quz = single(baz, qux))]
+        pub struct Foo {
+            bar: String,
+            baz: Option<String>,
+            qux: Option<String>,
+        }
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .baz("world".to_string())
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: "Hello".to_string(),
+                baz: Some("world".to_string()),
+                qux: None,
+            }
+        );
+    }
+
+    #[test]
+    fn group_members_with_field_group() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(quz = at_least(2, bar, baz), quux = all_or_none(qux))]
+        pub struct Foo {
+            bar: Option<String>,
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
+            #[builder(group = quux)]
+            corge: Option<String>,
+        }
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux("world".to_string())
+            .corge("!".to_string())
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: Some("Hello".to_string()),
+                baz: None,
+                qux: Some("world".to_string()),
+                corge: Some("!".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn group_members_unnamed() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(quz = between(1, 2, _0, bar, _2))]
+        #[builder(solver = counting)]
+        pub struct Foo(
+            Option<String>,
+            #[builder(name = bar)] Option<String>,
+            Option<String>,
+        );
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            ._2("world".to_string())
+            .build();
+        assert_eq!(
+            foo,
+            Foo(None, Some("Hello".to_string()), Some("world".to_string()))
+        );
+    }
+
+    #[test]
+    fn group_solver_auto() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(quz = exact(1))]
+        #[builder(solver = auto)]
+        pub struct Foo {
+            #[builder(group = quz)]
+            a: Option<u8>,
+            #[builder(group = quz)]
+            b: Option<u8>,
+            #[builder(group = quz)]
+            c: Option<u8>,
+            #[builder(group = quz)]
+            d: Option<u8>,
+            #[builder(group = quz)]
+            e: Option<u8>,
+            #[builder(group = quz)]
+            f: Option<u8>,
+            #[builder(group = quz)]
+            g: Option<u8>,
+            #[builder(group = quz)]
+            h: Option<u8>,
+            #[builder(group = quz)]
+            i: Option<u8>,
+            #[builder(group = quz)]
+            j: Option<u8>,
+            #[builder(group = quz)]
+            k: Option<u8>,
+            #[builder(group = quz)]
+            l: Option<u8>,
+            #[builder(group = quz)]
+            m: Option<u8>,
+            #[builder(group = quz)]
+            n: Option<u8>,
+            #[builder(group = quz)]
+            o: Option<u8>,
+        }
+
+        let foo = Foo::builder().h(8).build();
+        assert_eq!(
+            foo,
+            Foo {
+                h: Some(8),
+                ..Foo::default()
+            }
+        );
+    }
+
+    #[test]
+    fn group_multiple_member() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(baz = single)]
+        pub struct Foo {
+            #[builder(group = baz)]
+            bar: Option<String>,
+            #[builder(group = baz)]
+            qux: Option<String>,
+        }
 
         let expected = Foo {
-            bar: Bar {
-                baz: "Hello world!".to_string(),
-            },
+            bar: Some("Hello world!".to_string()),
+            qux: None,
+        };
+        let foo = Foo::builder().bar("Hello world!".to_string()).build();
+        assert_eq!(expected, foo);
+
+        let expected = Foo {
+            bar: None,
+            qux: Some("Hello world!".to_string()),
+        };
+        let foo = Foo::builder().qux("Hello world!".to_string()).build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn group_and_mandatory() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(quz = single)]
+        pub struct Foo {
+            #[builder(group = quz)]
+            bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            qux: String,
+        }
+
+        let expected = Foo {
+            bar: Some("Hello".to_string()),
+            baz: None,
+            qux: "world!".to_string(),
+        };
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux("world!".to_string())
+            .build();
+        assert_eq!(expected, foo);
+
+        let expected = Foo {
+            bar: None,
+            baz: Some("Hello".to_string()),
+            qux: "world!".to_string(),
         };
+
         let foo = Foo::builder()
-            .bar(|builder| builder.baz("Hello world!".to_string()).build())
+            .qux("world!".to_string())
+            .baz("Hello".to_string())
             .build();
         assert_eq!(expected, foo);
     }
 
     #[test]
-    fn optional_propagate() {
+    fn group_and_option_mandatory() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(quz = single)]
         pub struct Foo {
-            #[builder(propagate)]
-            bar: Option<Bar>,
+            #[builder(group = quz)]
+            bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(mandatory)]
+            qux: Option<String>,
         }
 
+        let expected = Foo {
+            bar: Some("Hello".to_string()),
+            baz: None,
+            qux: Some("world!".to_string()),
+        };
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux("world!".to_string())
+            .build();
+        assert_eq!(expected, foo);
+
+        let expected = Foo {
+            bar: None,
+            baz: Some("Hello".to_string()),
+            qux: Some("world!".to_string()),
+        };
+
+        let foo = Foo::builder()
+            .qux("world!".to_string())
+            .baz("Hello".to_string())
+            .build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn group_at_least() {
         #[derive(Debug, Default, PartialEq, Eq, Builder)]
-        pub struct Bar {
-            baz: String,
+        #[groups(quz = at_least(2))]
+        pub struct Foo {
+            #[builder(group = quz)]
+            bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
+        }
+
+        let expected = Foo {
+            bar: Some("Hello".to_string()),
+            baz: None,
+            qux: Some("world!".to_string()),
+        };
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux("world!".to_string())
+            .build();
+        assert_eq!(expected, foo);
+
+        let expected = Foo {
+            bar: None,
+            baz: Some("Hello".to_string()),
+            qux: Some("world!".to_string()),
+        };
+
+        let foo = Foo::builder()
+            .qux("world!".to_string())
+            .baz("Hello".to_string())
+            .build();
+        assert_eq!(expected, foo);
+
+        let expected = Foo {
+            bar: Some("Hello".to_string()),
+            baz: Some("world".to_string()),
+            qux: Some("!".to_string()),
+        };
+
+        let foo = Foo::builder()
+            .qux("!".to_string())
+            .baz("world".to_string())
+            .bar("Hello".to_string())
+            .build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn group_at_most() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        #[groups(quz = at_most(2))]
+        pub struct Foo {
+            #[builder(group = quz)]
+            bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
+        }
+
+        let expected = Foo {
+            bar: Some("Hello".to_string()),
+            baz: None,
+            qux: Some("world!".to_string()),
+        };
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux("world!".to_string())
+            .build();
+        assert_eq!(expected, foo);
+
+        let expected = Foo {
+            bar: None,
+            baz: Some("Hello".to_string()),
+            qux: Some("world!".to_string()),
+        };
+
+        let foo = Foo::builder()
+            .qux("world!".to_string())
+            .baz("Hello".to_string())
+            .build();
+        assert_eq!(expected, foo);
+
+        let expected = Foo {
+            bar: None,
+            baz: Some("Hello world!".to_string()),
+            qux: None,
+        };
+        let foo = Foo::builder().baz("Hello world!".to_string()).build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn single_generic_added_default() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Foo<A>
+        where
+            A: Default,
+        {
+            bar: A,
+        }
+
+        let expected = Foo::<String> {
+            bar: "Hello world!".to_string(),
+        };
+        let foo = Foo::<String>::builder()
+            .bar("Hello world!".to_string())
+            .build();
+        assert_eq!(expected, foo);
+
+        let foo: Foo<String> = Foo::builder().bar("Hello world!".to_string()).build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn single_generic_multiple_mandatory_added_default() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Foo<A>
+        where
+            A: Default,
+        {
+            bar: A,
+            baz: A,
+        }
+
+        let expected = Foo::<String> {
+            bar: "Hello world!".to_string(),
+            baz: "Hello world!".to_string(),
+        };
+        let foo = Foo::<String>::builder()
+            .bar("Hello world!".to_string())
+            .baz("Hello world!".to_string())
+            .build();
+        assert_eq!(expected, foo);
+
+        let foo: Foo<String> = Foo::builder()
+            .bar("Hello world!".to_string())
+            .baz("Hello world!".to_string())
+            .build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn single_generic_multiple_mandatory() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Foo<A> {
+            bar: A,
+            baz: A,
+        }
+
+        let expected = Foo::<String> {
+            bar: "Hello world!".to_string(),
+            baz: "Hello world!".to_string(),
+        };
+        let foo = Foo::<String>::builder()
+            .bar("Hello world!".to_string())
+            .baz("Hello world!".to_string())
+            .build();
+        assert_eq!(expected, foo);
+
+        let foo: Foo<String> = Foo::builder()
+            .bar("Hello world!".to_string())
+            .baz("Hello world!".to_string())
+            .build();
+
+        assert_eq!(expected, foo);
+
+        let foo = Foo::builder()
+            .bar("Hello world!".to_string())
+            .baz("Hello world!".to_string())
+            .build();
+
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn multiple_generic_multiple_mandatory() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Foo<A, B> {
+            bar: A,
+            baz: B,
+        }
+
+        let expected = Foo::<String, &str> {
+            bar: "Hello world!".to_string(),
+            baz: "Hello world!",
+        };
+        let foo = Foo::<String, &str>::builder()
+            .bar("Hello world!".to_string())
+            .baz("Hello world!")
+            .build();
+        assert_eq!(expected, foo);
+
+        let foo: Foo<String, &str> = Foo::builder()
+            .bar("Hello world!".to_string())
+            .baz("Hello world!")
+            .build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn multiple_generic_with_const_multiple_mandatory() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Foo<A, B, const C: usize> {
+            bar: A,
+            baz: B,
+        }
+
+        let expected = Foo::<String, &str, 0> {
+            bar: "Hello world!".to_string(),
+            baz: "Hello world!",
+        };
+        let foo = Foo::<String, &str, 0>::builder()
+            .bar("Hello world!".to_string())
+            .baz("Hello world!")
+            .build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn single_propagate() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Foo {
+            #[builder(propagate)]
+            bar: Bar,
+        }
+
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Bar {
+            baz: String,
+        }
+
+        let expected = Foo {
+            bar: Bar {
+                baz: "Hello world!".to_string(),
+            },
+        };
+        let foo = Foo::builder()
+            .bar_with(|builder| builder.baz("Hello world!".to_string()))
+            .build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn optional_propagate() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Foo {
+            #[builder(propagate)]
+            bar: Option<Bar>,
+        }
+
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Bar {
+            baz: String,
+        }
+
+        let expected = Foo {
+            bar: Some(Bar {
+                baz: "Hello world!".to_string(),
+            }),
+        };
+        let foo = Foo::builder()
+            .bar_with(|builder| builder.baz("Hello world!".to_string()))
+            .build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn propagate_direct_setter() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(propagate)]
+            bar: Bar,
+            #[builder(propagate)]
+            baz: Option<Bar>,
+        }
+
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Bar {
+            qux: String,
+        }
+
+        let bar = Bar {
+            qux: "Hello".to_string(),
+        };
+        let foo = Foo::builder()
+            .bar(bar)
+            .baz(Some(Bar {
+                qux: "world!".to_string(),
+            }))
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: Bar {
+                    qux: "Hello".to_string(),
+                },
+                baz: Some(Bar {
+                    qux: "world!".to_string(),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn propagate_into_suffix() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(propagate = using, into)]
+            bar: Bar,
+        }
+
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Bar {
+            baz: String,
+        }
+
+        impl From<&str> for Bar {
+            fn from(baz: &str) -> Self {
+                Bar {
+                    baz: baz.to_string(),
+                }
+            }
+        }
+
+        let foo = Foo::builder().bar("Hello world!").build();
+        assert_eq!(foo.bar.baz, "Hello world!");
+
+        let foo = Foo::builder()
+            .bar_using(|builder| builder.baz("Hello world!".to_string()))
+            .build();
+        assert_eq!(foo.bar.baz, "Hello world!");
+    }
+
+    #[test]
+    fn propagate_validate() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(propagate, validate = validate_bar)]
+            bar: Bar,
+        }
+
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Bar {
+            baz: String,
+        }
+
+        fn validate_bar(bar: &Bar) -> Result<(), String> {
+            if bar.baz.is_empty() {
+                Err("baz is empty".to_string())
+            } else {
+                Ok(())
+            }
+        }
+
+        let foo = Foo::builder()
+            .try_bar_with(|builder| builder.baz("Hello world!".to_string()))
+            .unwrap()
+            .build();
+        assert_eq!(foo.bar.baz, "Hello world!");
+
+        let result = Foo::builder().try_bar_with(|builder| builder.baz(String::new()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn propagate_validated_child() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(propagate)]
+            bar: Bar,
+            #[builder(propagate)]
+            qux: Option<Bar>,
+        }
+
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(validate = validate_bar, error = String)]
+        pub struct Bar {
+            baz: String,
+        }
+
+        fn validate_bar(bar: &Bar) -> Result<(), String> {
+            if bar.baz.is_empty() {
+                Err("baz is empty".to_string())
+            } else {
+                Ok(())
+            }
+        }
+
+        let foo = Foo::builder()
+            .bar_with(|builder| builder.baz("Hello".to_string()))
+            .unwrap()
+            .qux_with(|builder| builder.baz("world!".to_string()))
+            .unwrap()
+            .build();
+        assert_eq!(foo.bar.baz, "Hello");
+        assert_eq!(foo.qux.unwrap().baz, "world!");
+
+        let result = Foo::builder().bar_with(|builder| builder.baz(String::new()));
+        assert_eq!(result.err(), Some("baz is empty".to_string()));
+    }
+
+    #[test]
+    fn propagate_grouped_child() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(propagate)]
+            bar: Bar,
+        }
+
+        #[derive(Debug, PartialEq, Builder)]
+        #[groups(quz = single)]
+        pub struct Bar {
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
+        }
+
+        let foo = Foo::builder()
+            .bar_with(|builder| builder.qux("Hello world!".to_string()))
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: Bar {
+                    baz: None,
+                    qux: Some("Hello world!".to_string()),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn propagate_solver_compiler() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(solver = compiler)]
+        pub struct Foo {
+            #[builder(propagate)]
+            bar: Bar,
+        }
+
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(solver = compiler)]
+        #[groups(quz = single)]
+        pub struct Bar {
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
+        }
+
+        let foo = Foo::builder()
+            .bar_with(|builder| builder.baz("Hello world!".to_string()))
+            .build();
+        assert_eq!(foo.bar.baz, Some("Hello world!".to_string()));
+    }
+
+    #[test]
+    fn propagate_capture_owned() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Foo {
+            #[builder(propagate)]
+            bar: Bar,
+        }
+
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Bar {
+            baz: String,
+        }
+
+        let expected = Foo {
+            bar: Bar {
+                baz: "Hello world!".to_string(),
+            },
+        };
+        let baz = "Hello world!".to_string();
+        let foo = Foo::builder()
+            .bar_with(move |builder| builder.baz(baz))
+            .build();
+        assert_eq!(expected, foo);
+    }
+
+    #[test]
+    fn propagate_capture_borrowed() {
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Foo {
+            #[builder(propagate)]
+            bar: Option<Bar>,
+        }
+
+        #[derive(Debug, Default, PartialEq, Eq, Builder)]
+        pub struct Bar {
+            baz: String,
+        }
+
+        let expected = Foo {
+            bar: Some(Bar {
+                baz: "Hello world!".to_string(),
+            }),
+        };
+        let baz = "Hello world!";
+        let foo = Foo::builder()
+            .bar_with(|builder| builder.baz(baz.to_string()))
+            .build();
+        assert_eq!(expected, foo);
+        assert_eq!(baz, "Hello world!");
+    }
+
+    #[test]
+    fn no_other_derive_necessary() {
+        #[derive(Builder)]
+        pub struct Foo {
+            bar: String,
+        }
+        let foo = Foo::builder().bar("Hello world!".to_string()).build();
+        assert_eq!(foo.bar, "Hello world!");
+    }
+
+    #[test]
+    fn skip_field() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            bar: String,
+            #[builder(skip)]
+            baz: Option<String>,
+        }
+        let expected = Foo {
+            bar: "Hello world!".to_string(),
+            baz: None,
+        };
+        let foo = Foo::builder().bar("Hello world!".to_string()).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn reference() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<'a> {
+            bar: &'a str,
+        }
+        let expected = Foo {
+            bar: "Hello world!",
+        };
+        let foo = Foo::builder().bar("Hello world!").build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn mut_reference() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<'a> {
+            bar: &'a mut str,
+        }
+        let mut my_str = "Hello world!".to_string();
+        let mut my_str_clone = my_str.clone();
+        let expected = Foo {
+            bar: my_str.as_mut_str(),
+        };
+        let foo = Foo::builder().bar(my_str_clone.as_mut_str()).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn const_generic() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<const SIZE: usize> {
+            bar: [usize; SIZE],
+        }
+        let expected = Foo { bar: [1, 2, 3] };
+        let foo = Foo::builder().bar([1, 2, 3]).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn into() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(into)]
+            bar: String,
+        }
+        let expected = Foo {
+            bar: "Hello world!".to_string(),
+        };
+        let foo = Foo::builder().bar("Hello world!").build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn into_optional() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(into)]
+            bar: Option<String>,
+        }
+        let expected = Foo {
+            bar: Some("Hello world!".to_string()),
+        };
+        let foo = Foo::builder().bar(Some("Hello world!")).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn assume_into() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(into)]
+        pub struct Foo {
+            bar: String,
+        }
+        let expected = Foo {
+            bar: "Hello world!".to_string(),
+        };
+        let foo = Foo::builder().bar("Hello world!").build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn into_other_strct() {
+        #[derive(Debug, PartialEq)]
+        pub struct MyStruct {
+            my_str: String,
+        }
+        impl MyStruct {
+            fn new(my_str: String) -> Self {
+                Self { my_str }
+            }
+        }
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(into)]
+        pub struct Foo {
+            bar: MyStruct,
+        }
+        let expected = Foo {
+            bar: MyStruct {
+                my_str: "Hello world!".to_string(),
+            },
+        };
+        let foo = Foo::builder()
+            .bar(MyStruct::new("Hello world!".to_string()))
+            .build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn asref() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<'a> {
+            #[builder(asref)]
+            bar: &'a str,
+        }
+        let m_str = "Hello world!".to_string();
+
+        let expected = Foo { bar: &m_str };
+
+        let foo = Foo::builder().bar(&m_str).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn as_ref() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<'a> {
+            #[builder(asref)]
+            bar: &'a str,
+        }
+        let m_str = "Hello world!".to_string();
+
+        let expected = Foo { bar: &m_str };
+
+        let foo = Foo::builder().bar(&m_str).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn asref_optional() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<'a> {
+            #[builder(asref)]
+            bar: Option<&'a str>,
+        }
+        let m_str = "Hello world!".to_string();
+
+        let expected = Foo {
+            bar: Some(m_str.as_str()),
+        };
+
+        let foo = Foo::builder().bar(Some(&m_str)).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn asmut() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<'a> {
+            #[builder(asmut)]
+            bar: &'a mut str,
+        }
+        let mut m_str = "Hello world!".to_string();
+
+        let expected = Foo {
+            bar: &mut m_str.clone(),
+        };
+
+        let foo = Foo::builder().bar(&mut m_str).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn as_mut() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<'a> {
+            #[builder(as_mut)]
+            bar: &'a mut str,
+        }
+        let mut m_str = "Hello world!".to_string();
+
+        let expected = Foo {
+            bar: &mut m_str.clone(),
+        };
+
+        let foo = Foo::builder().bar(&mut m_str).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn asmut_optional() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<'a> {
+            #[builder(asmut)]
+            bar: Option<&'a mut str>,
+        }
+        let mut m_str = "Hello world!".to_string();
+        let mut m_str_clone = m_str.clone();
+
+        let expected = Foo {
+            bar: Some(&mut m_str),
+        };
+
+        let foo = Foo::builder().bar(Some(&mut m_str_clone)).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn enum_variants() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub enum Foo {
+            Bar { baz: String, qux: Option<String> },
+            Quz,
+        }
+
+        let expected = Foo::Bar {
+            baz: "Hello world!".to_string(),
+            qux: None,
+        };
+        let foo = Foo::bar_builder().baz("Hello world!".to_string()).build();
+        assert_eq!(foo, expected);
+
+        let foo = Foo::builder().bar().baz("Hello world!".to_string()).build();
+        assert_eq!(foo, expected);
+
+        let foo = Foo::builder().quz().build();
+        assert_eq!(foo, Foo::Quz);
+    }
+
+    #[test]
+    fn enum_variant_group() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(into)]
+        pub enum Foo {
+            #[groups(quz = single)]
+            Bar {
+                #[builder(group = quz)]
+                baz: Option<String>,
+                #[builder(group = quz)]
+                qux: Option<String>,
+            },
+        }
+
+        let expected = Foo::Bar {
+            baz: None,
+            qux: Some("Hello world!".to_string()),
+        };
+        let foo = Foo::bar_builder().qux("Hello world!").build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn enum_generic() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub enum Foo<A, B> {
+            Bar { baz: A },
+            Qux { quz: B },
+        }
+
+        let foo: Foo<String, u8> = Foo::builder().bar().baz("Hello world!".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo::Bar {
+                baz: "Hello world!".to_string()
+            }
+        );
+
+        let foo = Foo::<String, u8>::qux_builder().quz(42).build();
+        assert_eq!(foo, Foo::Qux { quz: 42 });
+    }
+
+    #[test]
+    fn tuple_struct() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo(String, Option<String>);
+
+        let expected = Foo("Hello".to_string(), Some("world!".to_string()));
+        let foo = Foo::builder()
+            ._0("Hello".to_string())
+            ._1(Some("world!".to_string()))
+            .build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn tuple_struct_named() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[groups(quz = single)]
+        pub struct Foo(
+            #[builder(name = bar)] String,
+            #[builder(name = baz, group = quz)] Option<String>,
+            #[builder(name = qux, group = quz)] Option<String>,
+        );
+
+        let expected = Foo("Hello".to_string(), None, Some("world!".to_string()));
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux("world!".to_string())
+            .build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn newtype() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo(#[builder(into)] String);
+
+        let foo = Foo::builder()._0("Hello world!").build();
+        assert_eq!(foo, Foo("Hello world!".to_string()));
+    }
+
+    #[test]
+    fn enum_tuple_variant() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub enum Foo {
+            Bar(#[builder(name = baz)] String, Option<u8>),
+        }
+
+        let foo = Foo::bar_builder().baz("Hello world!".to_string()).build();
+        assert_eq!(foo, Foo::Bar("Hello world!".to_string(), None));
+    }
+
+    #[test]
+    fn default() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            bar: String,
+            #[builder(default)]
+            baz: u32,
+            #[builder(default = vec!["Hello world!".to_string()])]
+            qux: Vec<String>,
+        }
+
+        let expected = Foo {
+            bar: "Hello".to_string(),
+            baz: 0,
+            qux: vec!["Hello world!".to_string()],
+        };
+        let foo = Foo::builder().bar("Hello".to_string()).build();
+        assert_eq!(foo, expected);
+
+        let expected = Foo {
+            bar: "Hello".to_string(),
+            baz: 42,
+            qux: Vec::new(),
+        };
+        let foo = Foo::builder()
+            .baz(42)
+            .qux(Vec::new())
+            .bar("Hello".to_string())
+            .build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn default_into() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(default = "Hello world!".to_string(), into)]
+            bar: String,
+        }
+
+        let foo = Foo::builder().build();
+        assert_eq!(foo.bar, "Hello world!");
+
+        let foo = Foo::builder().bar("Hello").build();
+        assert_eq!(foo.bar, "Hello");
+    }
+
+    #[test]
+    fn skip_field_default() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            bar: String,
+            #[builder(skip)]
+            baz: u32,
+            #[builder(skip = vec!["Hello world!".to_string()])]
+            qux: Vec<String>,
+        }
+        let expected = Foo {
+            bar: "Hello world!".to_string(),
+            baz: 0,
+            qux: vec!["Hello world!".to_string()],
+        };
+        let foo = Foo::builder().bar("Hello world!".to_string()).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn skip_phantom_data() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo<A> {
+            bar: String,
+            baz: std::marker::PhantomData<A>,
+        }
+        let expected = Foo::<u8> {
+            bar: "Hello world!".to_string(),
+            baz: std::marker::PhantomData,
+        };
+        let foo = Foo::<u8>::builder().bar("Hello world!".to_string()).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn builder_name() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(name = MyBuilder, data_name = "MyData", vis = "pub(crate)")]
+        pub struct Foo {
+            bar: String,
+        }
+
+        let builder: MyBuilder<false> = Foo::builder();
+        let foo = builder.bar("Hello world!".to_string()).build();
+        assert_eq!(foo.bar, "Hello world!");
+
+        let _data = MyData::default();
+    }
+
+    #[test]
+    fn enum_builder_name() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(name = FooSelector)]
+        pub enum Foo {
+            #[builder(name = BarBuilder)]
+            Bar {
+                baz: String,
+            },
+            Qux,
+        }
+
+        let selector: FooSelector = Foo::builder();
+        let builder: BarBuilder<false> = selector.bar();
+        let foo = builder.baz("Hello world!".to_string()).build();
+        assert_eq!(
+            foo,
+            Foo::Bar {
+                baz: "Hello world!".to_string()
+            }
+        );
+
+        let builder: FooQuxBuilder = Foo::qux_builder();
+        assert_eq!(builder.build(), Foo::Qux);
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct ModuleBar(String);
+
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(module)]
+    pub struct ModuleFoo {
+        bar: ModuleBar,
+        baz: Option<String>,
+    }
+
+    #[test]
+    fn module() {
+        let expected = ModuleFoo {
+            bar: ModuleBar("Hello world!".to_string()),
+            baz: None,
+        };
+        let builder: ModuleFooBuilder<false> = ModuleFoo::builder();
+        let foo = builder.bar(ModuleBar("Hello world!".to_string())).build();
+        assert_eq!(foo, expected);
+    }
+
+    #[test]
+    fn build_fn() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(build_fn(name = finish, vis = "pub(crate)", boxed, arc, into))]
+        pub struct Foo {
+            build: String,
+        }
+
+        #[derive(Debug, PartialEq)]
+        pub struct Wrapper(Foo);
+
+        impl From<Foo> for Wrapper {
+            fn from(foo: Foo) -> Self {
+                Wrapper(foo)
+            }
+        }
+
+        let expected = Foo {
+            build: "Hello world!".to_string(),
+        };
+
+        let foo = Foo::builder().build("Hello world!".to_string()).finish();
+        assert_eq!(foo, expected);
+
+        let foo: Box<Foo> = Foo::builder()
+            .build("Hello world!".to_string())
+            .finish_boxed();
+        assert_eq!(*foo, expected);
+
+        let foo: std::sync::Arc<Foo> = Foo::builder()
+            .build("Hello world!".to_string())
+            .finish_arc();
+        assert_eq!(*foo, expected);
+
+        let foo = Foo::builder()
+            .build("Hello world!".to_string())
+            .finish_into::<Wrapper>();
+        assert_eq!(foo, Wrapper(expected));
+    }
+
+    #[test]
+    fn build_fn_solver_compiler() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[groups(quz = single)]
+        #[builder(solver = compiler, build_fn(name = finish, boxed))]
+        pub struct Foo {
+            #[builder(group = quz)]
+            bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+        }
+
+        let expected = Foo {
+            bar: Some("Hello world!".to_string()),
+            baz: None,
+        };
+
+        let foo = Foo::builder()
+            .bar("Hello world!".to_string())
+            .finish_boxed();
+        assert_eq!(*foo, expected);
+    }
+
+    #[test]
+    fn validate() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(validate = validate_range)]
+        pub struct Range {
+            min: u32,
+            max: u32,
+        }
+
+        fn validate_range(range: &Range) -> Result<(), String> {
+            if range.min <= range.max {
+                Ok(())
+            } else {
+                Err(format!("{} is larger than {}", range.min, range.max))
+            }
+        }
+
+        let range = Range::builder().min(1).max(2).try_build().unwrap();
+        assert_eq!(range, Range { min: 1, max: 2 });
+
+        let err = Range::builder().min(2).max(1).try_build().unwrap_err();
+        assert_eq!(err.to_string(), "2 is larger than 1");
+    }
+
+    #[test]
+    fn validate_error() {
+        #[derive(Debug, PartialEq)]
+        pub struct PortError;
+
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(validate = validate_port, error = PortError, build_fn(boxed))]
+        pub struct Server {
+            port: u16,
+        }
+
+        fn validate_port(server: &Server) -> Result<(), PortError> {
+            if server.port == 0 {
+                Err(PortError)
+            } else {
+                Ok(())
+            }
+        }
+
+        let server = Server::builder().port(8080).try_build();
+        assert_eq!(server, Ok(Server { port: 8080 }));
+
+        let server = Server::builder().port(0).try_build_boxed();
+        assert_eq!(server, Err(PortError));
+    }
+
+    #[test]
+    fn validate_field() {
+        #[derive(Debug, PartialEq)]
+        pub struct PortError(u16);
+
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Server {
+            #[builder(validate = validate_port, error = PortError)]
+            port: u16,
+            #[builder(validate = validate_host, into)]
+            host: Option<String>,
+        }
+
+        fn validate_port(port: &u16) -> Result<(), PortError> {
+            if *port == 0 {
+                Err(PortError(*port))
+            } else {
+                Ok(())
+            }
+        }
+
+        fn validate_host(host: &str) -> Result<(), &'static str> {
+            if host.is_empty() {
+                Err("The host can't be empty")
+            } else {
+                Ok(())
+            }
+        }
+
+        let server = Server::builder()
+            .try_port(8080)
+            .unwrap()
+            .try_host(Some("localhost"))
+            .unwrap()
+            .build();
+        assert_eq!(
+            server,
+            Server {
+                port: 8080,
+                host: Some("localhost".to_string())
+            }
+        );
+
+        let builder: Result<ServerBuilder<true>, PortError> = Server::builder().try_port(0);
+        assert_eq!(builder.err(), Some(PortError(0)));
+
+        let builder = Server::builder().try_host(Some(""));
+        assert_eq!(
+            builder.err().map(|err| err.to_string()),
+            Some("The host can't be empty".to_string())
+        );
+
+        let server = Server::builder()
+            .try_port(8080)
+            .unwrap()
+            .try_host(None::<String>)
+            .unwrap()
+            .build();
+        assert_eq!(
+            server,
+            Server {
+                port: 8080,
+                host: None
+            }
+        );
+    }
+
+    #[test]
+    fn setter_prefix() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[builder(setter_prefix = "with_")]
+        pub struct Foo {
+            bar: String,
+            #[builder(rename = set_baz)]
+            baz: Option<String>,
+        }
+
+        let foo = Foo::builder()
+            .with_bar("Hello".to_string())
+            .set_baz(Some("world!".to_string()))
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: "Hello".to_string(),
+                baz: Some("world!".to_string())
+            }
+        );
+    }
+
+    mod setter_vis {
+        use const_typed_builder::Builder;
+
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            pub bar: String,
+            #[builder(vis = "pub")]
+            baz: Option<String>,
+            qux: Option<String>,
+        }
+
+        impl Foo {
+            pub fn with_qux(qux: &str) -> FooBuilder<false> {
+                Foo::builder().qux(Some(qux.to_string()))
+            }
+
+            pub fn qux(&self) -> Option<&str> {
+                self.qux.as_deref()
+            }
+        }
+    }
+
+    #[test]
+    fn setter_vis() {
+        let foo = setter_vis::Foo::with_qux("!")
+            .bar("Hello".to_string())
+            .baz(Some("world".to_string()))
+            .build();
+        assert_eq!(foo.bar, "Hello");
+        assert_eq!(foo.qux(), Some("!"));
+    }
+
+    #[test]
+    fn each() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Command {
+            program: String,
+            #[builder(each = arg)]
+            args: Vec<String>,
+            #[builder(each = env, into)]
+            envs: std::collections::BTreeMap<String, String>,
+            #[builder(each = flags, default = vec!['v'])]
+            flags: Vec<char>,
+        }
+
+        let command = Command::builder()
+            .arg("-l".to_string())
+            .program("ls".to_string())
+            .arg("-a".to_string())
+            .env(("LANG".to_string(), "C".to_string()))
+            .flags('h')
+            .build();
+        assert_eq!(
+            command,
+            Command {
+                program: "ls".to_string(),
+                args: vec!["-l".to_string(), "-a".to_string()],
+                envs: [("LANG".to_string(), "C".to_string())].into(),
+                flags: vec!['v', 'h'],
+            }
+        );
+
+        let command = Command::builder()
+            .program("ls".to_string())
+            .args(vec!["-l".to_string()])
+            .arg("-a".to_string())
+            .build();
+        assert_eq!(command.args, vec!["-l".to_string(), "-a".to_string()]);
+        assert!(command.envs.is_empty());
+        assert_eq!(command.flags, vec!['v']);
+    }
+
+    #[test]
+    fn each_into() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(each = bar, into)]
+            bars: std::collections::HashSet<String>,
+        }
+
+        let foo = Foo::builder()
+            .bar("Hello")
+            .bar("world!")
+            .bar("Hello")
+            .build();
+        assert_eq!(foo.bars.len(), 2);
+        assert!(foo.bars.contains("world!"));
+    }
+
+    #[test]
+    fn each_min_max() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Config {
+            #[builder(each = endpoint, min = 1)]
+            endpoints: Vec<String>,
+            #[builder(each = retry, max = 2)]
+            retries: Vec<u32>,
+            #[builder(each = mirror, min = 1, max = 2)]
+            mirrors: Vec<String>,
+        }
+
+        let builder: ConfigBuilder<0, 0, 0> = Config::builder();
+        let builder: ConfigBuilder<1, 0, 1> =
+            builder.endpoint("a".to_string()).mirror("b".to_string());
+        let builder: ConfigBuilder<1, 0, 1> = builder.endpoint("c".to_string());
+        let builder: ConfigBuilder<1, 2, 2> = builder.retry(1).retry(2).mirror("d".to_string());
+        let config = builder.build();
+        assert_eq!(
+            config,
+            Config {
+                endpoints: vec!["a".to_string(), "c".to_string()],
+                retries: vec![1, 2],
+                mirrors: vec!["b".to_string(), "d".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn each_min_solver_compiler() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[groups(quz = single)]
+        #[builder(solver = compiler)]
+        pub struct Foo {
+            #[builder(each = bar, min = 2)]
+            bars: Vec<u32>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
+        }
+
+        let foo = Foo::builder()
+            .bar(1)
+            .baz("Hello world!".to_string())
+            .bar(2)
+            .bar(3)
+            .build();
+        assert_eq!(foo.bars, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn maybe() {
+        #[derive(Debug, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(mandatory, maybe)]
+            bar: Option<String>,
+            #[builder(maybe, into)]
+            baz: Option<String>,
+        }
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .baz("world!")
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: Some("Hello".to_string()),
+                baz: Some("world!".to_string()),
+            }
+        );
+
+        let foo = Foo::builder()
+            .maybe_bar(None)
+            .maybe_baz(None::<&str>)
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: None,
+                baz: None
+            }
+        );
+    }
+
+    #[test]
+    fn maybe_container() {
+        #[derive(Debug, PartialEq, Builder)]
+        #[groups(quz = single)]
+        #[builder(maybe)]
+        pub struct Foo<'a> {
+            #[builder(asref)]
+            bar: Option<&'a str>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
         }
 
-        let expected = Foo {
-            bar: Some(Bar {
-                baz: "Hello world!".to_string(),
-            }),
-        };
+        let name = "Hello".to_string();
+        let foo = Foo::builder().bar(&name).baz("world!".to_string()).build();
+        assert_eq!(foo.bar, Some("Hello"));
+
         let foo = Foo::builder()
-            .bar(|builder| Some(builder.baz("Hello world!".to_string()).build()))
+            .maybe_bar(None::<&String>)
+            .qux("world!".to_string())
             .build();
-        assert_eq!(expected, foo);
-    }
-
-    #[test]
-    fn no_other_derive_necessary() {
-        #[derive(Builder)]
-        pub struct Foo {
-            bar: String,
-        }
-        let foo = Foo::builder().bar("Hello world!".to_string()).build();
-        assert_eq!(foo.bar, "Hello world!");
+        assert_eq!(foo.bar, None);
     }
 
     #[test]
-    fn skip_field() {
+    fn clear() {
         #[derive(Debug, PartialEq, Builder)]
+        #[groups(quz = single)]
         pub struct Foo {
             bar: String,
-            #[builder(skip)]
+            #[builder(group = quz)]
             baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
+            #[builder(each = item, min = 1)]
+            items: Vec<u32>,
         }
-        let expected = Foo {
-            bar: "Hello world!".to_string(),
-            baz: None,
-        };
-        let foo = Foo::builder().bar("Hello world!".to_string()).build();
-        assert_eq!(foo, expected);
-    }
 
-    #[test]
-    fn reference() {
-        #[derive(Debug, PartialEq, Builder)]
-        pub struct Foo<'a> {
-            bar: &'a str,
-        }
-        let expected = Foo {
-            bar: "Hello world!",
-        };
-        let foo = Foo::builder().bar("Hello world!").build();
-        assert_eq!(foo, expected);
+        let builder: FooBuilder<true, true, false, 1> = Foo::builder()
+            .bar("Hello".to_string())
+            .baz("world!".to_string())
+            .item(1);
+        let builder: FooBuilder<true, false, false, 0> = builder.clear_baz().clear_items();
+        let foo = builder.qux("world!".to_string()).item(2).build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: "Hello".to_string(),
+                baz: None,
+                qux: Some("world!".to_string()),
+                items: vec![2],
+            }
+        );
     }
 
     #[test]
-    fn mut_reference() {
+    fn clear_names() {
         #[derive(Debug, PartialEq, Builder)]
-        pub struct Foo<'a> {
-            bar: &'a mut str,
-        }
-        let mut my_str = "Hello world!".to_string();
-        let mut my_str_clone = my_str.clone();
-        let expected = Foo {
-            bar: my_str.as_mut_str(),
-        };
-        let foo = Foo::builder().bar(my_str_clone.as_mut_str()).build();
-        assert_eq!(foo, expected);
+        pub struct Foo(
+            Option<String>,
+            #[builder(rename = set_bar)] Option<String>,
+            #[builder(name = baz)] Option<String>,
+        );
+
+        let foo = Foo::builder()
+            ._0(Some("Hello".to_string()))
+            .set_bar(Some("world".to_string()))
+            .baz(Some("!".to_string()))
+            .clear__0()
+            .clear__1()
+            .clear_baz()
+            .build();
+        assert_eq!(foo, Foo(None, None, None));
     }
 
     #[test]
-    fn const_generic() {
+    fn clear_solver_compiler() {
         #[derive(Debug, PartialEq, Builder)]
-        pub struct Foo<const SIZE: usize> {
-            bar: [usize; SIZE],
+        #[groups(quz = single)]
+        #[builder(solver = compiler)]
+        pub struct Foo {
+            #[builder(group = quz)]
+            bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            qux: Option<String>,
         }
-        let expected = Foo { bar: [1, 2, 3] };
-        let foo = Foo::builder().bar([1, 2, 3]).build();
-        assert_eq!(foo, expected);
+
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux(Some("world!".to_string()))
+            .clear_bar()
+            .clear_qux()
+            .baz("Hello".to_string())
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: None,
+                baz: Some("Hello".to_string()),
+                qux: None,
+            }
+        );
     }
 
     #[test]
-    fn into() {
+    fn once() {
         #[derive(Debug, PartialEq, Builder)]
+        #[builder(once)]
         pub struct Foo {
-            #[builder(into)]
             bar: String,
+            baz: Option<String>,
+            #[builder(default = 42)]
+            qux: u32,
+            #[builder(each = item)]
+            items: Vec<u32>,
         }
-        let expected = Foo {
-            bar: "Hello world!".to_string(),
-        };
-        let foo = Foo::builder().bar("Hello world!").build();
-        assert_eq!(foo, expected);
+
+        let builder: FooBuilder<false, false, false> = Foo::builder();
+        let builder: FooBuilder<true, true, false> = builder
+            .baz(Some("world!".to_string()))
+            .bar("Hello".to_string())
+            .item(1)
+            .item(2);
+        let foo = builder.build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: "Hello".to_string(),
+                baz: Some("world!".to_string()),
+                qux: 42,
+                items: vec![1, 2],
+            }
+        );
+
+        let foo = Foo::builder()
+            .qux(1)
+            .clear_qux()
+            .qux(2)
+            .bar("Hello".to_string())
+            .build();
+        assert_eq!(foo.qux, 2);
     }
 
     #[test]
-    fn into_optional() {
+    fn once_field_solver_compiler() {
         #[derive(Debug, PartialEq, Builder)]
+        #[groups(quz = single)]
+        #[builder(solver = compiler)]
         pub struct Foo {
-            #[builder(into)]
+            #[builder(once)]
             bar: Option<String>,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
         }
-        let expected = Foo {
-            bar: Some("Hello world!".to_string()),
-        };
-        let foo = Foo::builder().bar(Some("Hello world!")).build();
-        assert_eq!(foo, expected);
+
+        let foo = Foo::builder().baz("world!".to_string()).build();
+        assert_eq!(foo.bar, None);
+
+        let foo = Foo::builder()
+            .bar(Some("Hello".to_string()))
+            .baz("world!".to_string())
+            .build();
+        assert_eq!(foo.bar, Some("Hello".to_string()));
     }
 
     #[test]
-    fn assume_into() {
+    fn getters() {
         #[derive(Debug, PartialEq, Builder)]
-        #[builder(into)]
+        #[groups(quz = single)]
         pub struct Foo {
             bar: String,
+            baz: Option<String>,
+            #[builder(default = 42)]
+            qux: u32,
+            #[builder(each = item)]
+            items: Vec<u32>,
+            #[builder(group = quz)]
+            quux: Option<String>,
+            #[builder(group = quz)]
+            quuz: Option<String>,
         }
-        let expected = Foo {
-            bar: "Hello world!".to_string(),
-        };
-        let foo = Foo::builder().bar("Hello world!").build();
-        assert_eq!(foo, expected);
+
+        let builder = Foo::builder();
+        assert_eq!(builder.baz_ref(), None);
+        assert_eq!(builder.qux_ref(), None);
+        assert!(builder.items_ref().is_empty());
+
+        let builder = builder
+            .bar("Hello".to_string())
+            .baz(Some("world!".to_string()))
+            .qux(1)
+            .item(2)
+            .quux("quux".to_string());
+        assert_eq!(builder.bar_ref(), "Hello");
+        assert_eq!(builder.baz_ref(), Some(&"world!".to_string()));
+        assert_eq!(builder.qux_ref(), Some(&1));
+        assert_eq!(builder.items_ref(), &vec![2]);
+        assert_eq!(builder.quux_ref(), "quux");
+
+        let path = format!("{}.txt", builder.bar_ref());
+        let foo = builder.baz(Some(path)).build();
+        assert_eq!(foo.baz, Some("Hello.txt".to_string()));
     }
 
     #[test]
-    fn into_other_strct() {
-        #[derive(Debug, PartialEq)]
-        pub struct MyStruct {
-            my_str: String,
-        }
-        impl MyStruct {
-            fn new(my_str: String) -> Self {
-                Self { my_str }
-            }
-        }
+    fn getters_mandatory_option() {
         #[derive(Debug, PartialEq, Builder)]
-        #[builder(into)]
         pub struct Foo {
-            bar: MyStruct,
+            #[builder(mandatory)]
+            bar: Option<String>,
         }
-        let expected = Foo {
-            bar: MyStruct {
-                my_str: "Hello world!".to_string(),
-            },
-        };
-        let foo = Foo::builder()
-            .bar(MyStruct::new("Hello world!".to_string()))
-            .build();
-        assert_eq!(foo, expected);
+
+        let builder = Foo::builder().bar("Hello".to_string());
+        assert_eq!(builder.bar_ref(), &Some("Hello".to_string()));
     }
 
     #[test]
-    fn asref() {
-        #[derive(Debug, PartialEq, Builder)]
-        pub struct Foo<'a> {
-            #[builder(asref)]
-            bar: &'a str,
+    fn from_target() {
+        #[derive(Debug, Clone, PartialEq, Builder)]
+        pub struct Foo {
+            bar: String,
+            baz: Option<String>,
+            #[builder(default = 42)]
+            qux: u32,
+            #[builder(each = item)]
+            items: Vec<u32>,
+            #[builder(skip)]
+            quz: u32,
         }
-        let m_str = "Hello world!".to_string();
 
-        let expected = Foo { bar: &m_str };
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux(1)
+            .item(2)
+            .build();
 
-        let foo = Foo::builder().bar(&m_str).build();
-        assert_eq!(foo, expected);
+        let builder = FooBuilder::from(foo.clone());
+        assert_eq!(builder.build(), foo);
+
+        let builder: FooBuilder<true> = foo.into();
+        let foo = builder
+            .baz(Some("world!".to_string()))
+            .clear_bar()
+            .bar("Hi".to_string())
+            .item(3)
+            .build();
+        assert_eq!(
+            foo,
+            Foo {
+                bar: "Hi".to_string(),
+                baz: Some("world!".to_string()),
+                qux: 1,
+                items: vec![2, 3],
+                quz: 0,
+            }
+        );
     }
 
     #[test]
-    fn as_ref() {
-        #[derive(Debug, PartialEq, Builder)]
-        pub struct Foo<'a> {
-            #[builder(asref)]
-            bar: &'a str,
+    fn from_target_group() {
+        #[derive(Debug, Clone, PartialEq, Builder)]
+        #[groups(quz = single)]
+        pub struct Foo {
+            bar: String,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
         }
-        let m_str = "Hello world!".to_string();
 
-        let expected = Foo { bar: &m_str };
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux("world!".to_string())
+            .build();
 
-        let foo = Foo::builder().bar(&m_str).build();
-        assert_eq!(foo, expected);
+        let builder: Result<FooBuilder<true, true, false>, Foo> = foo.clone().try_into();
+        assert_eq!(builder.err(), Some(foo.clone()));
+
+        let builder: FooBuilder<true, false, true> = foo.clone().try_into().unwrap();
+        let foo = builder.clear_qux().baz("world!".to_string()).build();
+        assert_eq!(foo.baz, Some("world!".to_string()));
+        assert_eq!(foo.qux, None);
     }
 
     #[test]
-    fn asref_optional() {
-        #[derive(Debug, PartialEq, Builder)]
-        pub struct Foo<'a> {
-            #[builder(asref)]
-            bar: Option<&'a str>,
+    fn from_target_group_solver_compiler() {
+        #[derive(Debug, Clone, PartialEq, Builder)]
+        #[groups(quz = single)]
+        #[builder(solver = compiler)]
+        pub struct Foo {
+            bar: String,
+            #[builder(group = quz)]
+            baz: Option<String>,
+            #[builder(group = quz)]
+            qux: Option<String>,
         }
-        let m_str = "Hello world!".to_string();
 
-        let expected = Foo {
-            bar: Some(m_str.as_str()),
-        };
+        let foo = Foo::builder()
+            .bar("Hello".to_string())
+            .qux("world!".to_string())
+            .build();
 
-        let foo = Foo::builder().bar(Some(&m_str)).build();
-        assert_eq!(foo, expected);
-    }
+        let builder: Result<FooBuilder<true, true, false>, Foo> = foo.clone().try_into();
+        assert_eq!(builder.err(), Some(foo.clone()));
 
-    #[test]
-    fn asmut() {
-        #[derive(Debug, PartialEq, Builder)]
-        pub struct Foo<'a> {
-            #[builder(asmut)]
-            bar: &'a mut str,
-        }
-        let mut m_str = "Hello world!".to_string();
+        let builder: FooBuilder<true, false, true> = foo.clone().try_into().unwrap();
+        assert_eq!(builder.build(), foo);
 
-        let expected = Foo {
-            bar: &mut m_str.clone(),
+        let foo = Foo {
+            bar: "Hello".to_string(),
+            baz: Some("world".to_string()),
+            qux: Some("!".to_string()),
         };
-
-        let foo = Foo::builder().bar(&mut m_str).build();
-        assert_eq!(foo, expected);
+        let builder: Result<FooBuilder<true, true, true>, Foo> = foo.clone().try_into();
+        assert_eq!(builder.err(), Some(foo));
     }
 
     #[test]
-    fn as_mut() {
-        #[derive(Debug, PartialEq, Builder)]
-        pub struct Foo<'a> {
-            #[builder(as_mut)]
-            bar: &'a mut str,
+    fn from_target_counted() {
+        #[derive(Debug, Clone, PartialEq, Builder)]
+        pub struct Foo {
+            #[builder(each = item, min = 1)]
+            items: Vec<u32>,
         }
-        let mut m_str = "Hello world!".to_string();
 
-        let expected = Foo {
-            bar: &mut m_str.clone(),
-        };
+        let foo = Foo::builder().item(1).item(2).build();
+        let builder: FooBuilder<1> = foo.clone().try_into().unwrap();
+        assert_eq!(builder.item(3).build().items, vec![1, 2, 3]);
 
-        let foo = Foo::builder().bar(&mut m_str).build();
-        assert_eq!(foo, expected);
+        let foo = Foo { items: Vec::new() };
+        let builder: Result<FooBuilder<1>, Foo> = foo.clone().try_into();
+        assert_eq!(builder.err(), Some(foo));
     }
 
     #[test]
-    fn asmut_optional() {
-        #[derive(Debug, PartialEq, Builder)]
-        pub struct Foo<'a> {
-            #[builder(asmut)]
-            bar: Option<&'a mut str>,
+    fn from_target_enum() {
+        #[derive(Debug, Clone, PartialEq, Builder)]
+        pub enum Foo {
+            Bar { baz: String, qux: Option<String> },
+            Quz(u32),
         }
-        let mut m_str = "Hello world!".to_string();
-        let mut m_str_clone = m_str.clone();
 
-        let expected = Foo {
-            bar: Some(&mut m_str),
-        };
+        let foo = Foo::bar_builder().baz("Hello".to_string()).build();
+        let builder: FooBarBuilder<true> = foo.try_into().unwrap();
+        let foo = builder.qux(Some("world!".to_string())).build();
+        assert_eq!(
+            foo,
+            Foo::Bar {
+                baz: "Hello".to_string(),
+                qux: Some("world!".to_string()),
+            }
+        );
 
-        let foo = Foo::builder().bar(Some(&mut m_str_clone)).build();
-        assert_eq!(foo, expected);
+        let builder: Result<FooBarBuilder<true>, Foo> = Foo::Quz(1).try_into();
+        assert_eq!(builder.err(), Some(Foo::Quz(1)));
+
+        let builder: FooQuzBuilder<true> = Foo::Quz(1).try_into().unwrap();
+        assert_eq!(builder.build(), Foo::Quz(1));
     }
 }
@@ -79,6 +79,26 @@
 ///     .build();
 /// ```
 ///
+/// Fields that are not an `Option` can be made optional by giving them a default value with `default` or `default = expr`.
+/// If the field is not set, it's initialized with `Default::default()` or `expr` respectively.
+///
+/// ```
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Foo {
+///     bar: String,            // Mandatory
+///     #[builder(default)]
+///     baz: u32,               // Optional, defaults to 0
+///     #[builder(default = vec!["Hello".to_string()])]
+///     qux: Vec<String>,       // Optional, defaults to `vec!["Hello"]`
+/// }
+///
+/// let foo = Foo::builder()
+///     .bar("Hello".to_string())
+///     .baz(42)
+///     .build();
+/// ```
+///
 /// Or you can assume everything is mandatory altogether with `assume_mandatory` and `optional`.
 ///
 /// ```
@@ -95,6 +115,7 @@
 /// ```
 ///
 /// You can also skip fields. This can be used if you still want to deserialize deprecated fields for instance.
+/// Skipped fields are set to `Default::default()`, or to `expr` when using `skip = expr`. Fields of type `PhantomData` are skipped by default.
 /// ```compile_fail
 /// # use const_typed_builder::Builder;
 /// #[derive(Builder)]
@@ -109,11 +130,49 @@
 ///     .baz("World".to_string()) // This function does not exist
 ///     .build();
 /// ```
+///
+/// Optional fields of type `Option` take an `Option` in their setter, while mandatory fields of type `Option` take the inner type, and can't be set to `None`.
+/// Using `#[builder(maybe)]` both kinds of fields get a setter that takes the inner type, and a setter called `maybe_{field}` that takes an `Option`.
+/// This can be added to a field, or to the struct to apply it to all optional and mandatory fields of type `Option`.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[builder(maybe)]
+/// pub struct Foo {
+///     #[builder(mandatory)]
+///     bar: Option<String>,
+///     baz: Option<String>,
+/// }
+///
+/// let foo = Foo::builder()
+///     .maybe_bar(None)
+///     .baz("Hello world!".to_string())
+///     .build();
+/// ```
+///
+/// Mandatory and grouped fields can only be set once, while optional fields can be overwritten.
+/// Using `#[builder(once)]` optional fields can only be set once as well, it can be added to a field or to the struct.
+/// ```compile_fail
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[builder(once)]
+/// pub struct Foo {
+///     bar: Option<String>,
+/// }
+///
+/// let foo = Foo::builder()
+///     .bar(Some("Hello".to_string()))
+///     .bar(Some("world!".to_string())) // This function does not exist after `bar` is set
+///     .build();
+/// ```
+///
 /// ## 3. Grouped Fields
 ///
 /// Fields can be grouped together, and constraints can be applied to these groups.
 /// Groups allow you to ensure that a certain combination of fields is provided together.
-/// There are four types of groups: `single`, `at_least`, `at_most`, and `exact`.
+/// There are six types of groups: `single`, `at_least`, `at_most`, `exact`, `between` and `all_or_none`.
 ///
 /// **All** fields that are grouped need to be an `Option` type.
 ///
@@ -121,6 +180,8 @@
 /// - `at_least(n)`: Requires at least `n` fields in the group to be provided.
 /// - `at_most(n)`: Allows at most `n` fields in the group to be provided.
 /// - `exact(n)`: Requires exactly `n` fields in the group to be provided.
+/// - `between(min, max)`: Requires at least `min` and at most `max` fields in the group to be provided.
+/// - `all_or_none`: Requires either all fields in the group or none of them to be provided.
 ///
 /// The range of n is 1..=k, where k is the amount of fields that are associated with this group.
 /// Errors will be emitted if the group can never be valid, or can be replaced by `skip` or `mandatory`.
@@ -180,6 +241,40 @@
 ///     .baz("World".to_string())
 ///     .build();
 /// ```
+/// Valid construction because either all fields in `my_group` or none of them are provided:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[groups(my_group = all_or_none)]
+/// pub struct Foo {
+///     #[builder(group = my_group)]
+///     bar: Option<String>,
+///     #[builder(group = my_group)]
+///     baz: Option<String>,
+/// }
+///
+/// let foo = Foo::builder()
+///     .bar("Hello".to_string())
+///     .baz("World".to_string())
+///     .build();
+/// let foo = Foo::builder().build();
+/// ```
+/// Invalid construction because only one of the fields in `my_group` is provided:
+/// ```compile_fail
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[groups(my_group = all_or_none)]
+/// pub struct Foo {
+///     #[builder(group = my_group)]
+///     bar: Option<String>,
+///     #[builder(group = my_group)]
+///     baz: Option<String>,
+/// }
+///
+/// let foo = Foo::builder()
+///     .bar("Hello".to_string())
+///     .build();
+/// ```
 /// You can also add multiple groups to each field
 ///
 /// Valid construction because at least 2 fields in 'least' are provided, and 'fred' had to be provided to validate the group 'most':
@@ -207,12 +302,35 @@
 ///     .fred("!".to_string())
 ///     .build();
 /// ```
+/// The members of a group can also be listed by name where the group is defined, after the arguments of the group type.
+/// This can be combined with `#[builder(group = ...)]` on the fields themselves.
+///
+/// Valid construction because only `password` is provided:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[groups(auth = single(token, password, cert))]
+/// pub struct Login {
+///     user: String,
+///     token: Option<String>,
+///     password: Option<String>,
+///     cert: Option<String>,
+/// }
+///
+/// let login = Login::builder()
+///     .user("admin".to_string())
+///     .password("hunter2".to_string())
+///     .build();
+/// ```
 ///
 /// ## 4. Propagating Builder for Complex Structs
 ///
 /// If a field in the struct is of a complex type that also derives the `Builder` trait, you can propagate the construction of that field to its builder using the `propagate` attribute.
-///
-/// *Disclaimer* I'm still working a lot on this feature and I may change how it works. (I would love for the user to return the builder at the end of the function instead of calling `.builder()` by hand)
+/// Next to the normal setter, a setter called `{field}_with` is generated that takes a closure, which receives the builder of the field and can capture variables from its environment.
+/// The closure returns the builder in any state in which it can be built, and the parent builder builds it.
+/// Returning a builder in a state in which it can't be built yet results in a compile error. Optional fields are set to `Some` of the built value.
+/// The suffix of the setter can be changed using `#[builder(propagate = suffix)]`, and propagate can be combined with `into`.
+/// If the builder of the field is validated, the setter returns a `Result` with the error of that validation.
 ///
 /// ### Example:
 /// Valid construction with complex struct 'Bar' created within 'Foo'
@@ -230,7 +348,26 @@
 /// }
 ///
 /// let foo = Foo::builder()
-///     .bar(|builder| builder.baz("Hello world!".to_string()).build())
+///     .bar_with(|builder| builder.baz("Hello world!".to_string()))
+///     .build();
+/// ```
+///
+/// Invalid construction where the builder of 'Bar' is returned before `baz` is set:
+/// ```compile_fail
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Foo {
+///     #[builder(propagate)]
+///     bar: Bar,
+/// }
+///
+/// #[derive(Builder)]
+/// pub struct Bar {
+///     baz: String,
+/// }
+///
+/// let foo = Foo::builder()
+///     .bar_with(|builder| builder)
 ///     .build();
 /// ```
 ///
@@ -257,6 +394,419 @@
 ///     .build();
 /// ```
 ///
+/// ## 7. Enums
+///
+/// The `Builder` derive macro can also be used on enums. A separate builder is generated for every variant,
+/// which checks the fields of that variant exactly like the builder of a struct would. Groups are defined on the variant itself.
+/// The builder of a variant can be selected from `Foo::builder()`, or created directly with `Foo::{variant}_builder()`.
+///
+/// ### Example:
+/// Valid construction of the variant `Foo::Bar`
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub enum Foo {
+///     #[groups(quz = single)]
+///     Bar {
+///         baz: String,
+///         #[builder(group = quz)]
+///         qux: Option<String>,
+///         #[builder(group = quz)]
+///         quz: Option<String>,
+///     },
+///     Fred,
+/// }
+///
+/// let foo = Foo::builder()
+///     .bar()
+///     .baz("Hello".to_string())
+///     .qux("world!".to_string())
+///     .build();
+///
+/// let fred = Foo::fred_builder().build();
+/// ```
+///
+/// ## 8. Tuple Structs
+///
+/// Tuple structs, newtypes and tuple variants are supported as well. The setters of unnamed fields are called `_0`, `_1`, etc.,
+/// unless they are given a name using `#[builder(name = ...)]`.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Foo(#[builder(name = bar)] String, Option<String>);
+///
+/// let foo = Foo::builder()
+///     .bar("Hello".to_string())
+///     ._1(Some("world!".to_string()))
+///     .build();
+/// ```
+///
+/// ## 9. Generated Items
+///
+/// The builder is called `{Struct}Builder` and has the visibility of the struct by default, this can be changed using
+/// `#[builder(name = FooBuilder, vis = "pub(crate)")]`. The builder stores its values in a data struct called `{Struct}Data`,
+/// which can be renamed using `#[builder(data_name = FooData)]`. The data struct has the same visibility as the builder.
+/// A builder that is less visible than the struct can't be exposed through the `Builder` trait, so `builder()` is generated
+/// as an inherent method with the visibility of the builder instead. Such a struct can't be used as a field with `#[builder(propagate)]`.
+///
+/// With `#[builder(module)]` the builder and the data struct are generated in a private module, and only the builder is re-exported.
+/// This prevents the data struct from being used to bypass the checks of the builder. Everything the struct uses has to be reachable
+/// from the module it is declared in, so this can't be used for structs that are declared inside of a function body.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[builder(name = FooMaker, module)]
+/// pub struct Foo {
+///     bar: String,
+/// }
+///
+/// # fn main() {
+/// let foo = FooMaker::new()
+///     .bar("Hello world!".to_string())
+///     .build();
+/// # }
+/// ```
+///
+/// ## 10. Build Method
+///
+/// The name and visibility of the build method can be changed using `#[builder(build_fn(name = finish, vis = "pub(crate)"))]`,
+/// which is useful if the struct has a field called `build`. Adding `boxed`, `arc` or `into` generates the additional build methods
+/// `finish_boxed()`, `finish_arc()` and `finish_into::<T>()`, which return a `Box<Foo>`, an `Arc<Foo>` or any `T` that implements `From<Foo>`.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// # use std::sync::Arc;
+/// #[derive(Builder)]
+/// #[builder(build_fn(name = finish, arc))]
+/// pub struct Foo {
+///     build: String,
+/// }
+///
+/// let foo: Arc<Foo> = Foo::builder()
+///     .build("Hello world!".to_string())
+///     .finish_arc();
+/// ```
+///
+/// ## 11. Validation
+///
+/// The builder checks whether fields are set, but it can't check the relations between their values, like whether `min <= max`.
+/// Using `#[builder(validate = validate_foo)]` the target is passed to `validate_foo` before it's returned.
+/// The function receives a `&Foo` and returns a `Result<(), E>`. The build method is then called `try_build()`, and returns a `Result`.
+/// The error type defaults to `Box<dyn std::error::Error + Send + Sync>` and can be set using `#[builder(error = FooError)]`,
+/// the error of the function is converted using `From`.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Debug, Builder)]
+/// #[builder(validate = validate_range)]
+/// pub struct Range {
+///     min: u32,
+///     max: u32,
+/// }
+///
+/// fn validate_range(range: &Range) -> Result<(), String> {
+///     if range.min <= range.max {
+///         Ok(())
+///     } else {
+///         Err("min is larger than max".to_string())
+///     }
+/// }
+///
+/// let range = Range::builder().min(1).max(2).try_build();
+/// assert!(range.is_ok());
+///
+/// let range = Range::builder().min(2).max(1).try_build();
+/// assert!(range.is_err());
+/// ```
+///
+/// Fields can be validated when they're set as well, using `#[builder(validate = validate_bar)]` on the field.
+/// The setter is then called `try_bar()`, and returns a `Result` containing the builder.
+/// The validation function receives a reference to the value, also for fields of type `Option`, where it isn't called if the setter receives `None`.
+/// The error type defaults to the error type of the struct, and can be set using `#[builder(error = BarError)]` on the field.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Debug, Builder)]
+/// pub struct Server {
+///     #[builder(validate = validate_port)]
+///     port: u16,
+/// }
+///
+/// fn validate_port(port: &u16) -> Result<(), String> {
+///     if *port == 0 {
+///         Err("The port can't be 0".to_string())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// let server = Server::builder().try_port(8080).unwrap().build();
+/// assert!(Server::builder().try_port(0).is_err());
+/// ```
+///
+/// ## 12. Setters
+///
+/// A setter has the same name and visibility as its field by default, so private fields get private setters.
+/// The fields of enum variants don't have a visibility, so their setters get the visibility of the builder.
+/// A prefix can be added to the names of all setters using `#[builder(setter_prefix = "with_")]`.
+/// The name and visibility of a single setter can be changed using `#[builder(rename = set_bar, vis = "pub(crate)")]` on the field,
+/// the prefix isn't added to renamed setters.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[builder(setter_prefix = "with_")]
+/// pub struct Foo {
+///     bar: String,
+///     #[builder(rename = set_baz, vis = "pub(crate)")]
+///     baz: Option<String>,
+/// }
+///
+/// let foo = Foo::builder()
+///     .with_bar("Hello".to_string())
+///     .set_baz(Some("world!".to_string()))
+///     .build();
+/// ```
+///
+/// ## 13. Collections
+///
+/// Fields that contain a collection, like a `Vec`, a `HashSet` or a `BTreeMap`, can get a setter that adds a single item to the collection
+/// using `#[builder(each = item)]`. The collection starts out empty, or with the default of the field, so the field doesn't have to be set.
+/// The setter for the whole collection is still generated, unless it has the same name as the setter for a single item.
+/// The setter accepts `impl Into<Item>` if `into` is used.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Command {
+///     program: String,
+///     #[builder(each = arg, into)]
+///     args: Vec<String>,
+/// }
+///
+/// let command = Command::builder()
+///     .program("ls".to_string())
+///     .arg("-l")
+///     .arg("-a")
+///     .build();
+/// ```
+///
+/// The number of items in the collection can be limited using `#[builder(each = item, min = 1, max = 3)]`.
+/// The builder tracks the number of items that are added, so the build method is only available if it's within these bounds,
+/// and no more items can be added once the maximum is reached. The setter for the whole collection isn't generated for these fields.
+/// Because the builder counts the items that are added, fields with a default, sets and maps can't have a minimum or a maximum.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Config {
+///     #[builder(each = endpoint, min = 1, max = 3)]
+///     endpoints: Vec<String>,
+/// }
+///
+/// let config = Config::builder()
+///     .endpoint("localhost:8080".to_string())
+///     .endpoint("localhost:8081".to_string())
+///     .build();
+/// ```
+///
+/// ```compile_fail
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Config {
+///     #[builder(each = endpoint, min = 1, max = 3)]
+///     endpoints: Vec<String>,
+/// }
+///
+/// let config = Config::builder().build();
+/// ```
+///
+/// ## 14. Clearing Fields
+///
+/// Every field that can be set has a `clear_{field}()` method, which resets the field as if it was never set.
+/// The method is always named after the field, also if the setter is renamed, so the field `_0` of a tuple struct is cleared using `clear__0()`.
+/// An error is emitted if this name is already used by another method of the builder, like the setter of a field named `clear_bar`.
+/// The builder returns to the state in which the field isn't set, so the group rules are checked again when building.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[groups(auth = single)]
+/// pub struct Login {
+///     #[builder(group = auth)]
+///     token: Option<String>,
+///     #[builder(group = auth)]
+///     password: Option<String>,
+/// }
+///
+/// let login = Login::builder()
+///     .token("abc".to_string())
+///     .clear_token()
+///     .password("hunter2".to_string())
+///     .build();
+/// ```
+///
+/// ## 15. Getters
+///
+/// Every field that can be set has a `{field}_ref()` method, which returns a reference to its value while the builder is still being used.
+/// Mandatory and grouped fields only have this method once they are set, and it returns a reference to the value itself.
+/// The builder stores these values in an `Option` internally, but the type state guarantees that they are set whenever the method exists.
+/// Optional fields have this method in every state, and it returns an `Option` of a reference that is `None` if the field isn't set.
+/// Collections with a setter for each item return a reference to the collection.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct File {
+///     name: String,
+///     path: Option<String>,
+/// }
+///
+/// let builder = File::builder().name("foo".to_string());
+/// let path = format!("/tmp/{}.txt", builder.name_ref());
+/// let file = builder.path(Some(path)).build();
+/// ```
+///
+/// ## 16. Converting Into a Builder
+///
+/// An instance of the struct can be converted back into a builder in which every field is set, to create a copy with some fields changed.
+/// Optional fields can be set again directly, mandatory fields have to be cleared first.
+/// If the struct has groups, constraints or collections with a minimum or a maximum, the state of the builder depends on the values of the fields.
+/// In that case `TryFrom` is implemented for every state in which the builder can be built, which returns the instance if its values don't match the state.
+/// Enum variants always use `TryFrom`, which also returns the instance if it's a different variant.
+///
+/// ### Example:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Config {
+///     name: String,
+///     path: Option<String>,
+/// }
+///
+/// let config = Config::builder().name("foo".to_string()).build();
+/// let config = ConfigBuilder::from(config)
+///     .path(Some("/tmp".to_string()))
+///     .build();
+/// ```
+///
+/// ## 17. Field Constraints
+///
+/// Constraints between two fields can be defined on the fields themselves, which is often easier than defining a group.
+/// - `#[builder(requires = other)]`: The field `other` has to be set if this field is set.
+/// - `#[builder(conflicts_with = other)]`: The field `other` can't be set if this field is set, and vice versa.
+///   The setter of either field is no longer available once the other field is set.
+///
+/// Fields that are part of a constraint are tracked like grouped fields, so they have to be of type `Option` and can only be set once.
+/// They can't use `maybe` or `once`.
+/// Constraints can be combined with groups, and are checked by every solver.
+///
+/// ### Examples:
+///
+/// Valid construction because `tls_cert` is set together with `tls_key`, and `port` isn't set together with `socket_path`:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Server {
+///     #[builder(requires = tls_cert)]
+///     tls_key: Option<String>,
+///     tls_cert: Option<String>,
+///     #[builder(conflicts_with = port)]
+///     socket_path: Option<String>,
+///     port: Option<u16>,
+/// }
+///
+/// let server = Server::builder()
+///     .tls_key("key.pem".to_string())
+///     .tls_cert("cert.pem".to_string())
+///     .port(8080)
+///     .build();
+/// ```
+/// Invalid construction because `tls_key` requires `tls_cert`:
+/// ```compile_fail
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Server {
+///     #[builder(requires = tls_cert)]
+///     tls_key: Option<String>,
+///     tls_cert: Option<String>,
+/// }
+///
+/// let server = Server::builder()
+///     .tls_key("key.pem".to_string())
+///     .build();
+/// ```
+/// Invalid construction because the setter of `port` isn't available once `socket_path` is set:
+/// ```compile_fail
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// pub struct Server {
+///     #[builder(conflicts_with = port)]
+///     socket_path: Option<String>,
+///     port: Option<u16>,
+/// }
+///
+/// let server = Server::builder()
+///     .socket_path("/tmp/server.sock".to_string())
+///     .port(8080)
+///     .build();
+/// ```
+///
+/// ## 18. Constraint Expressions
+///
+/// Constraints that can't be expressed by counting the fields of a group can be defined on the struct using `#[constraint(...)]`.
+/// The expression combines the names of fields using `any(...)`, `all(...)` and `not(...)`, where a field is true if it's set.
+/// Groups are enforced on their own, so they can't be referenced by a constraint, but the fields of a group can.
+/// Every referenced field is tracked like a grouped field, so it has to be of type `Option`.
+/// Errors will be emitted if the expression can never be satisfied, and warnings if it's always satisfied.
+/// Expressions like `not(all(a, b))` remove the setter of either field once the other field is set, just like `conflicts_with`.
+///
+/// ### Examples:
+///
+/// Valid construction because either `a` is set, or both `b` and `c` are set:
+/// ```rust
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[constraint(any(all(a, not(b), not(c)), all(not(a), b, c)))]
+/// pub struct Foo {
+///     a: Option<String>,
+///     b: Option<String>,
+///     c: Option<String>,
+/// }
+///
+/// let foo = Foo::builder()
+///     .b("Hello".to_string())
+///     .c("World".to_string())
+///     .build();
+/// ```
+/// Invalid construction because only `b` is set:
+/// ```compile_fail
+/// # use const_typed_builder::Builder;
+/// #[derive(Builder)]
+/// #[constraint(any(all(a, not(b), not(c)), all(not(a), b, c)))]
+/// pub struct Foo {
+///     a: Option<String>,
+///     b: Option<String>,
+///     c: Option<String>,
+/// }
+///
+/// let foo = Foo::builder()
+///     .b("Hello".to_string())
+///     .build();
+/// ```
+///
 /// These are the key features and explanations of the `Builder` derive macro in Rust, along with examples illustrating each feature. This macro simplifies the process of creating builders for your structs while ensuring compile-time safety and correctness.
 pub use const_typed_builder_derive::Builder;
 /// The `Builder` trait facilitates the creation of builder patterns for Rust struct types. It provides a common interface for generating builders that enable the construction of instances of a struct with various configurations and compile-time validity checking.
@@ -264,3 +814,33 @@ pub trait Builder {
     type BuilderImpl;
     fn builder() -> Self::BuilderImpl;
 }
+
+/// The `Buildable` trait is implemented for every state of a generated builder in which it can be built,
+/// unless the build method is less visible than the builder using `#[builder(build_fn(vis = ...))]`.
+/// It's used by `#[builder(propagate)]`, where the closure returns the builder of the field, which is then built by the builder of the parent.
+pub trait Buildable {
+    type Target;
+    fn build(self) -> Self::Target;
+}
+
+/// The `Propagated` trait passes the value built by a [`Buildable`] to the setter of a propagated field.
+/// The value of a builder without validation is passed on directly. A validated builder returns a `Result`,
+/// in which case its error is returned instead of calling the setter.
+pub trait Propagated<T> {
+    type Output<O>;
+    fn map<O>(self, f: impl FnOnce(T) -> O) -> Self::Output<O>;
+}
+
+impl<T> Propagated<T> for T {
+    type Output<O> = O;
+    fn map<O>(self, f: impl FnOnce(T) -> O) -> Self::Output<O> {
+        f(self)
+    }
+}
+
+impl<T, E> Propagated<T> for Result<T, E> {
+    type Output<O> = Result<O, E>;
+    fn map<O>(self, f: impl FnOnce(T) -> O) -> Self::Output<O> {
+        self.map(f)
+    }
+}